@@ -212,16 +212,19 @@ export const withdrawController = async (
       data: {
         fundId: fund.id,
         type: 'WITHDRAWAL',
-        amount: BigInt(result.amount),
+        amount: BigInt(Math.round(result.withdrawalAmount * LAMPORTS_PER_SOL)),
         signature: result.transactionSignature,
         initiator: telegramId,
-        status: 'CONFIRMED',
+        // Queued redemptions are paid when the epoch settles
+        status: result.queued ? 'PENDING' : 'CONFIRMED',
       },
     });
 
     return res.status(200).json({
       success: true,
-      message: 'Withdrawal successful',
+      message: result.queued
+        ? `Withdrawal queued; it settles after ${result.settlesAfter}`
+        : 'Withdrawal successful',
       data: result,
     });
   } catch (error: any) {
@@ -368,7 +371,7 @@ export const getMyShares = async (
           totalContributions: allContributions.length,
           minContribution: blockchainFundInfo.minContribution,
           tradingFeeBps: blockchainFundInfo.tradingFeeBps,
-          isActive: blockchainFundInfo.isActive,
+          onChainStatus: blockchainFundInfo.status,
         },
 
         // User's contribution history (last 5)
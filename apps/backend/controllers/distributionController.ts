@@ -14,6 +14,7 @@ import * as anchor from "@coral-xyz/anchor";
 import crypto from "crypto";
 import bs58 from "bs58";
 import idl from "../../../contract/groupchat_fund/target/idl/groupchat_fund.json";
+import { getLiveFundPDA } from "../services/solanaServices/fundService";
 
 let connection: Connection;
 
@@ -140,11 +141,8 @@ async function fetchAccountWithRetry(
 /**
  * Derive PDAs for fund and member
  */
-function derivePDAs(program: anchor.Program, groupId: string, memberWallet: PublicKey) {
-  const [fundPDA] = PublicKey.findProgramAddressSync(
-    [Buffer.from("fund"), Buffer.from(groupId)],
-    program.programId
-  );
+async function derivePDAs(program: anchor.Program, groupId: string, memberWallet: PublicKey) {
+  const fundPDA = await getLiveFundPDA(groupId, program);
 
   const [memberPDA] = PublicKey.findProgramAddressSync(
     [Buffer.from("member"), fundPDA.toBuffer(), memberWallet.toBuffer()],
@@ -375,7 +373,7 @@ export const distributionController = {
       let onChainState = null;
       try {
         console.log("📊 Attempting to fetch updated on-chain state...");
-        const { fundPDA, memberPDA } = derivePDAs(program, groupId, memberWallet);
+        const { fundPDA, memberPDA } = await derivePDAs(program, groupId, memberWallet);
 
         const memberAccount = await fetchAccountWithRetry(program, "member", memberPDA).catch(err => {
           console.warn("Could not fetch member account:", err.message);
@@ -557,7 +555,7 @@ export const distributionController = {
       let onChainState = null;
       try {
         console.log("📊 Attempting to fetch updated on-chain state...");
-        const { fundPDA } = derivePDAs(program, groupId, memberWallet);
+        const { fundPDA } = await derivePDAs(program, groupId, memberWallet);
 
         const fundAccount = await fetchAccountWithRetry(program, "fund", fundPDA).catch(err => {
           console.warn("Could not fetch fund account:", err.message);
@@ -737,7 +735,7 @@ export const distributionController = {
       // TRY to fetch final fund state
       let onChainState = null;
       try {
-        const { fundPDA } = derivePDAs(program, groupId, PublicKey.default);
+        const { fundPDA } = await derivePDAs(program, groupId, PublicKey.default);
         const fundAccount = await fetchAccountWithRetry(program, "fund", fundPDA).catch(() => null);
 
         if (fundAccount) {
@@ -6,8 +6,8 @@ import {
   canExecuteTrade,
   getFundTradingInfo,
   getTradeHistory,
-  getFundPDA,
 } from "../services/solanaServices/tradeServices";
+import { getLiveFundPDA } from "../services/solanaServices/fundService";
 import * as anchor from "@coral-xyz/anchor";
 import IDL from "../../../contract/groupchat_fund/target/idl/groupchat_fund.json";
 import { GroupchatFund } from "../../../contract/groupchat_fund/target/types/groupchat_fund";
@@ -44,7 +44,7 @@ async function syncFundAfterTrade(groupId: string): Promise<void> {
     console.log("📊 Syncing fund state after trade...");
 
     const program = getProgramForReading();
-    const [fundPDA] = getFundPDA(groupId, program.programId);
+    const fundPDA = await getLiveFundPDA(groupId, program);
 
     // Fetch latest on-chain fund data
     const fundAccount = await program.account.fund.fetch(fundPDA);
@@ -52,7 +52,7 @@ async function syncFundAfterTrade(groupId: string): Promise<void> {
     console.log("📈 On-chain fund state:", {
       totalValue: fundAccount.totalValue.toNumber() / LAMPORTS_PER_SOL,
       totalShares: fundAccount.totalShares.toNumber(),
-      isActive: fundAccount.isActive,
+      status: Object.keys(fundAccount.status)[0],
     });
 
     // Convert BN to string for Prisma BigInt
@@ -236,7 +236,7 @@ export const executeTradeController = async (
       });
     }
 
-    if (error.message?.includes("FundNotActive")) {
+    if (error.message?.includes("InvalidFundStatus")) {
       return res.status(400).json({
         success: false,
         message: "Fund is not active",
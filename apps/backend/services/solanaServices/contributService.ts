@@ -13,6 +13,7 @@ import IDL from "../../../../contract/groupchat_fund/target/idl/groupchat_fund.j
 import { prisma } from "@repo/db";
 import bs58 from "bs58";
 import { decrypt } from "../utlis";
+import { getLiveFundPDA } from "./fundService";
 
 const connection = new Connection(
   process.env.SOLANA_RPC_URL || "https://api.devnet.solana.com",
@@ -101,10 +102,7 @@ export async function addMemberToFund(groupId: string, telegramId: string) {
     const program = getProgram(wallet);
 
     // Derive PDAs
-    const [fundPDA] = PublicKey.findProgramAddressSync(
-      [Buffer.from("fund"), Buffer.from(groupId)],
-      program.programId
-    );
+    const fundPDA = await getLiveFundPDA(groupId, program);
 
     const [memberPDA] = PublicKey.findProgramAddressSync(
       [
@@ -154,10 +152,7 @@ export async function checkMemberExists(
   try {
     const program = getProgramReadOnly();
 
-    const [fundPDA] = PublicKey.findProgramAddressSync(
-      [Buffer.from("fund"), Buffer.from(groupId)],
-      program.programId
-    );
+    const fundPDA = await getLiveFundPDA(groupId, program);
 
     const [memberPDA] = PublicKey.findProgramAddressSync(
       [Buffer.from("member"), fundPDA.toBuffer(), userPublicKey.toBuffer()],
@@ -200,10 +195,7 @@ export async function contributeToFund(
     const amountLamports = new BN(amountSol * LAMPORTS_PER_SOL);
 
     // Derive PDAs
-    const [fundPDA] = PublicKey.findProgramAddressSync(
-      [Buffer.from("fund"), Buffer.from(groupId)],
-      program.programId
-    );
+    const fundPDA = await getLiveFundPDA(groupId, program);
 
     const [memberPDA] = PublicKey.findProgramAddressSync(
       [
@@ -228,9 +220,11 @@ export async function contributeToFund(
     // Fetch fund account
     const fundAccount = await program.account.fund.fetch(fundPDA);
 
-    // Check if fund is active
-    if (!fundAccount.isActive) {
-      throw new Error("Fund is not active");
+    // Contributions are accepted while raising and while trading
+    if (!("fundraising" in fundAccount.status) && !("active" in fundAccount.status)) {
+      throw new Error(
+        `Fund is not accepting contributions (status: ${Object.keys(fundAccount.status)[0]})`
+      );
     }
 
     // Check minimum contribution
@@ -309,6 +303,8 @@ export async function contributeToFund(
 
 /**
  * Withdraw shares from fund
+ * While the fund trades, shares are queued with request_withdrawal and paid
+ * when the redemption epoch settles; once it winds down, withdraw pays at once.
  * Matches Rust: pub fn withdraw(ctx: Context<Withdraw>, shares_to_burn: u64)
  */
 export async function withdrawFromFund(
@@ -329,10 +325,7 @@ export async function withdrawFromFund(
     const program = getProgram(wallet);
 
     // Derive PDAs
-    const [fundPDA] = PublicKey.findProgramAddressSync(
-      [Buffer.from("fund"), Buffer.from(groupId)],
-      program.programId
-    );
+    const fundPDA = await getLiveFundPDA(groupId, program);
 
     const [memberPDA] = PublicKey.findProgramAddressSync(
       [
@@ -367,6 +360,21 @@ export async function withdrawFromFund(
       "SOL"
     );
 
+    if ("active" in fundAccount.status) {
+      return requestWithdrawal(program, fundPDA, memberPDA, userKeypair, sharesToBurnBN, {
+        withdrawalAmount: withdrawalAmountLamports / LAMPORTS_PER_SOL,
+        epochEndsAt: fundAccount.redemptions.startedAt
+          .add(fundAccount.redemptions.duration)
+          .toNumber(),
+      });
+    }
+
+    if (!("windingDown" in fundAccount.status)) {
+      throw new Error(
+        `Fund is not open for withdrawals (status: ${Object.keys(fundAccount.status)[0]})`
+      );
+    }
+
     // Execute withdraw instruction
     const tx = await program.methods
       .withdraw(sharesToBurnBN)
@@ -387,8 +395,10 @@ export async function withdrawFromFund(
 
     return {
       success: true,
+      queued: false,
       transactionSignature: tx,
       withdrawalAmount: withdrawalAmountLamports / LAMPORTS_PER_SOL,
+      settlesAfter: null,
       remainingShares: updatedMember.shares.toString(),
     };
   } catch (error: any) {
@@ -397,6 +407,53 @@ export async function withdrawFromFund(
   }
 }
 
+/**
+ * Queue shares for redemption at the end of the current epoch
+ * Matches Rust: pub fn request_withdrawal(ctx: Context<RequestWithdrawal>, shares: u64)
+ */
+async function requestWithdrawal(
+  program: Program<GroupchatFund>,
+  fundPDA: PublicKey,
+  memberPDA: PublicKey,
+  userKeypair: Keypair,
+  shares: BN,
+  estimate: { withdrawalAmount: number; epochEndsAt: number }
+) {
+  const [requestPDA] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("redemption"),
+      fundPDA.toBuffer(),
+      userKeypair.publicKey.toBuffer(),
+    ],
+    program.programId
+  );
+
+  const tx = await program.methods
+    .requestWithdrawal(shares)
+    .accountsPartial({
+      fund: fundPDA,
+      member: memberPDA,
+      request: requestPDA,
+      memberWallet: userKeypair.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([userKeypair])
+    .rpc();
+
+  console.log("✅ Withdrawal queued until the redemption epoch settles");
+  console.log("Transaction signature:", tx);
+
+  return {
+    success: true,
+    queued: true,
+    transactionSignature: tx,
+    // Paid at the epoch's closing NAV, which may differ from this estimate
+    withdrawalAmount: estimate.withdrawalAmount,
+    settlesAfter: new Date(estimate.epochEndsAt * 1000).toISOString(),
+    queuedShares: shares.toString(),
+  };
+}
+
 // ==================== QUERY OPERATIONS ====================
 
 /**
@@ -415,10 +472,7 @@ export async function getMemberShares(groupId: string, telegramId: string) {
     const userPublicKey = new PublicKey(user.walletAddress);
     const program = getProgramReadOnly();
 
-    const [fundPDA] = PublicKey.findProgramAddressSync(
-      [Buffer.from("fund"), Buffer.from(groupId)],
-      program.programId
-    );
+    const fundPDA = await getLiveFundPDA(groupId, program);
 
     const [memberPDA] = PublicKey.findProgramAddressSync(
       [Buffer.from("member"), fundPDA.toBuffer(), userPublicKey.toBuffer()],
@@ -459,10 +513,7 @@ export async function getFundInfo(groupId: string) {
   try {
     const program = getProgramReadOnly();
 
-    const [fundPDA] = PublicKey.findProgramAddressSync(
-      [Buffer.from("fund"), Buffer.from(groupId)],
-      program.programId
-    );
+    const fundPDA = await getLiveFundPDA(groupId, program);
 
     const fundAccount = await program.account.fund.fetch(fundPDA);
 
@@ -473,7 +524,7 @@ export async function getFundInfo(groupId: string) {
       minContribution:
         fundAccount.minContribution.toNumber() / LAMPORTS_PER_SOL,
       tradingFeeBps: fundAccount.tradingFeeBps,
-      isActive: fundAccount.isActive,
+      status: Object.keys(fundAccount.status)[0],
       fundName: fundAccount.fundName,
       authority: fundAccount.authority.toBase58(),
       groupId: fundAccount.groupId,
@@ -492,10 +543,7 @@ export async function getAllFundMembers(groupId: string) {
   try {
     const program = getProgramReadOnly();
 
-    const [fundPDA] = PublicKey.findProgramAddressSync(
-      [Buffer.from("fund"), Buffer.from(groupId)],
-      program.programId
-    );
+    const fundPDA = await getLiveFundPDA(groupId, program);
 
     // Fetch all member accounts for this fund
     const members = await program.account.member.all([
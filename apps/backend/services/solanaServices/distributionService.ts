@@ -3,6 +3,7 @@ import * as anchor from "@coral-xyz/anchor";
 import { Connection, PublicKey, SystemProgram } from "@solana/web3.js";
 import { BN } from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
+import { getLiveFundPDA } from "./fundService";
 
 export interface DistributionInfo {
   shares: string;
@@ -56,10 +57,7 @@ export async function calculateDistributionAmount({
   memberWallet: PublicKey;
 }): Promise<DistributionInfo> {
   try {
-    const [fundPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("fund"), Buffer.from(groupId)],
-      program.programId
-    );
+    const fundPda = await getLiveFundPDA(groupId, program);
 
     const [memberPda] = PublicKey.findProgramAddressSync(
       [Buffer.from("member"), fundPda.toBuffer(), memberWallet.toBuffer()],
@@ -136,10 +134,7 @@ export async function calculateProfitOnly({
   memberWallet: PublicKey;
 }): Promise<ProfitOnlyInfo> {
   try {
-    const [fundPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("fund"), Buffer.from(groupId)],
-      program.programId
-    );
+    const fundPda = await getLiveFundPDA(groupId, program);
 
     const [memberPda] = PublicKey.findProgramAddressSync(
       [Buffer.from("member"), fundPda.toBuffer(), memberWallet.toBuffer()],
@@ -200,10 +195,7 @@ export async function distributeValueToMember({
   memberWallet: PublicKey;
 }): Promise<string> {
   try {
-    const [fundPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("fund"), Buffer.from(groupId)],
-      program.programId
-    );
+    const fundPda = await getLiveFundPDA(groupId, program);
 
     const [memberPda] = PublicKey.findProgramAddressSync(
       [Buffer.from("member"), fundPda.toBuffer(), memberWallet.toBuffer()],
@@ -241,10 +233,7 @@ export async function distributeProfitToMember({
   memberWallet: PublicKey;
 }): Promise<string> {
   try {
-    const [fundPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("fund"), Buffer.from(groupId)],
-      program.programId
-    );
+    const fundPda = await getLiveFundPDA(groupId, program);
 
     const [memberPda] = PublicKey.findProgramAddressSync(
       [Buffer.from("member"), fundPda.toBuffer(), memberWallet.toBuffer()],
@@ -284,10 +273,7 @@ export async function distributeValueToAllMembers({
   memberKeypairs: Map<string, any>;
 }): Promise<MemberDistributionResult[]> {
   try {
-    const [fundPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("fund"), Buffer.from(groupId)],
-      program.programId
-    );
+    const fundPda = await getLiveFundPDA(groupId, program);
 
     const memberAccounts = await program.account.member.all([
       {
@@ -412,10 +398,7 @@ export async function getAllMembersDistributionInfo({
   }>
 > {
   try {
-    const [fundPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("fund"), Buffer.from(groupId)],
-      program.programId
-    );
+    const fundPda = await getLiveFundPDA(groupId, program);
 
     const memberAccounts = await program.account.member.all([
       {
@@ -503,10 +486,7 @@ export async function getAllMembersProfitInfo({
   }>
 > {
   try {
-    const [fundPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("fund"), Buffer.from(groupId)],
-      program.programId
-    );
+    const fundPda = await getLiveFundPDA(groupId, program);
 
     const memberAccounts = await program.account.member.all([
       {
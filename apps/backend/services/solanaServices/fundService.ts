@@ -10,6 +10,7 @@ import {
 import * as anchor from "@coral-xyz/anchor";
 import { Program, BN } from "@coral-xyz/anchor";
 import bs58 from "bs58";
+import { createHash } from "crypto";
 import { prisma } from "@repo/db";
 import { decrypt } from "../utlis";
 import { GroupchatFund } from "../../../../contract/groupchat_fund/target/types/groupchat_fund";
@@ -41,14 +42,64 @@ type SetupFundResult = FundInitResult & {
 
 // ==================== HELPER FUNCTIONS ====================
 
-// ✅ ADD THIS EXPORT
-export function getFundPDA(groupId: string, programId: PublicKey): [PublicKey, number] {
+// Group ids are hashed into seeds, so ids of any length fit
+function hashGroupId(groupId: string): Buffer {
+  return createHash("sha256").update(groupId).digest();
+}
+
+export function getGroupPDA(groupId: string, programId: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("group"), hashGroupId(groupId)],
+    programId
+  );
+}
+
+// Funds are addressed by group and by their index within the group; the
+// group registry counts the funds created so far and lists their addresses
+export function getFundPDA(
+  groupId: string,
+  programId: PublicKey,
+  fundIndex: number
+): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from("fund"), Buffer.from(groupId)],
+    [
+      Buffer.from("fund"),
+      hashGroupId(groupId),
+      new BN(fundIndex).toArrayLike(Buffer, "le", 8),
+    ],
     programId
   );
 }
 
+// The newest fund in the registry that is still open. Closing a fund
+// closes its account, so closed funds no longer resolve.
+async function findLiveFund(
+  program: Program<any>,
+  funds: PublicKey[]
+): Promise<PublicKey | null> {
+  for (let i = funds.length - 1; i >= 0; i--) {
+    const fund = await program.account.fund.fetchNullable(funds[i]);
+    if (fund && !("closed" in fund.status)) {
+      return funds[i];
+    }
+  }
+  return null;
+}
+
+// The bot runs one live fund per group; every fund operation targets it
+export async function getLiveFundPDA(
+  groupId: string,
+  program: Program<any>
+): Promise<PublicKey> {
+  const [groupPDA] = getGroupPDA(groupId, program.programId);
+  const group = await program.account.group.fetchNullable(groupPDA);
+  const fundPDA = group ? await findLiveFund(program, group.funds) : null;
+  if (!fundPDA) {
+    throw new Error(`No open fund found for group ${groupId}`);
+  }
+  return fundPDA;
+}
+
 // ✅ ADD THIS EXPORT TOO
 export function getMemberPDA(
   fundKey: PublicKey,
@@ -61,6 +112,16 @@ export function getMemberPDA(
   );
 }
 
+export function getAllowlistPDA(
+  fundKey: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("allowlist"), fundKey.toBuffer()],
+    programId
+  );
+}
+
 // Get user keypair from database
 export async function getUserKeypair(telegramId: string): Promise<Keypair | null> {
   try {
@@ -98,6 +159,15 @@ export async function getUserKeypair(telegramId: string): Promise<Keypair | null
   }
 }
 
+// Bot key the program accepts for group registration
+function getRegistrarKeypair(): Keypair {
+  const secret = process.env.REGISTRAR_PRIVATE_KEY;
+  if (!secret) {
+    throw new Error("REGISTRAR_PRIVATE_KEY is not set");
+  }
+  return Keypair.fromSecretKey(bs58.decode(secret));
+}
+
 // Ensure sufficient balance with airdrop
 async function ensureSufficientBalance(keypair: Keypair): Promise<void> {
   let balance = await connection.getBalance(keypair.publicKey);
@@ -180,40 +250,64 @@ export async function initializeFundOnBlockchain(
     const wallet = new anchor.Wallet(authorityKeypair);
     const program = getProgram(wallet);
 
-    // Use the exported function
-    const [fundPDA] = getFundPDA(groupId, program.programId);
+    const [groupPDA] = getGroupPDA(groupId, program.programId);
+    const existingGroup = await program.account.group.fetchNullable(groupPDA);
+
+    // Check if the group already has an open fund
+    const liveFundPDA = existingGroup
+      ? await findLiveFund(program, existingGroup.funds)
+      : null;
+    if (liveFundPDA) {
+      const existingFund = await program.account.fund.fetch(liveFundPDA);
+      console.log("⚠️ Fund already exists!");
+      return {
+        fundPdaAddress: liveFundPDA.toString(),
+        authority: existingFund.authority.toString(),
+        transactionSignature: null,
+        alreadyExists: true,
+      };
+    }
+    console.log("Fund doesn't exist, creating new one");
 
+    // The new fund takes the next index in the group
+    const [fundPDA] = getFundPDA(
+      groupId,
+      program.programId,
+      existingGroup ? existingGroup.fundCount.toNumber() : 0
+    );
     console.log("Fund PDA:", fundPDA.toString());
 
-    // Check if fund already exists
-    try {
-      const existingFund = await program.account.fund.fetch(fundPDA);
-      if (existingFund) {
-        console.log("⚠️ Fund already exists!");
-        return {
-          fundPdaAddress: fundPDA.toString(),
-          authority: existingFund.authority.toString(),
-          transactionSignature: null,
-          alreadyExists: true,
-        };
-      }
-    } catch (error: any) {
-      if (!error.message.includes("Account does not exist")) {
-        throw error;
-      }
-      console.log("Fund doesn't exist, creating new one");
+    // The group registry must exist before its first fund. Only the bot's
+    // registrar key can create it; the fund creator becomes its admin.
+    if (!existingGroup) {
+      console.log("Registering group...");
+      const registrarKeypair = getRegistrarKeypair();
+      await program.methods
+        .registerGroup(groupId, Array.from(hashGroupId(groupId)), [
+          authorityKeypair.publicKey,
+        ])
+        .accountsPartial({
+          group: groupPDA,
+          registrar: registrarKeypair.publicKey,
+          payer: authorityKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([registrarKeypair, authorityKeypair])
+        .rpc();
     }
 
-    // ✅ SIMPLIFIED: Initialize fund
     const tx = await program.methods
       .initializeFund(
-        groupId,
         fundName,
         new BN(minContribution),
-        tradingFeeBps
+        tradingFeeBps,
+        null
       )
       .accountsPartial({
+        group: groupPDA,
+        groupAdmin: authorityKeypair.publicKey,
         fund: fundPDA,
+        allowlist: getAllowlistPDA(fundPDA, program.programId)[0],
         authority: authorityKeypair.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -252,7 +346,7 @@ export async function closeFundOnBlockchain(
     const wallet = new anchor.Wallet(authorityKeypair);
     const program = getProgram(wallet);
 
-    const [fundPDA] = getFundPDA(groupId, program.programId);
+    const fundPDA = await getLiveFundPDA(groupId, program);
 
     console.log("Fund PDA:", fundPDA.toString());
 
@@ -324,7 +418,7 @@ export async function pauseFundOnBlockchain(
     const wallet = new anchor.Wallet(authorityKeypair);
     const program = getProgram(wallet);
 
-    const [fundPDA] = getFundPDA(groupId, program.programId);
+    const fundPDA = await getLiveFundPDA(groupId, program);
 
     const fundAccount = await program.account.fund.fetch(fundPDA);
     if (fundAccount.authority.toString() !== authorityKeypair.publicKey.toString()) {
@@ -368,7 +462,7 @@ export async function resumeFundOnBlockchain(
     const wallet = new anchor.Wallet(authorityKeypair);
     const program = getProgram(wallet);
 
-    const [fundPDA] = getFundPDA(groupId, program.programId);
+    const fundPDA = await getLiveFundPDA(groupId, program);
 
     const fundAccount = await program.account.fund.fetch(fundPDA);
     if (fundAccount.authority.toString() !== authorityKeypair.publicKey.toString()) {
@@ -413,7 +507,7 @@ export async function addMemberToFund(
     const wallet = new anchor.Wallet(memberKeypair);
     const program = getProgram(wallet);
 
-    const [fundPDA] = getFundPDA(groupId, program.programId);
+    const fundPDA = await getLiveFundPDA(groupId, program);
     const [memberPDA] = getMemberPDA(fundPDA, memberKeypair.publicKey, program.programId);
 
     console.log("Member PDA:", memberPDA.toString());
@@ -462,7 +556,7 @@ export async function executeTrade(
     const wallet = new anchor.Wallet(authorityKeypair);
     const program = getProgram(wallet);
 
-    const [fundPDA] = getFundPDA(groupId, program.programId);
+    const fundPDA = await getLiveFundPDA(groupId, program);
 
     const tx = await program.methods
       .executeTrade(
@@ -2,21 +2,17 @@
 
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
-import { Connection, PublicKey, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { Connection, LAMPORTS_PER_SOL } from "@solana/web3.js";
 import { GroupchatFund } from "../../../../contract/groupchat_fund/target/types/groupchat_fund";
 import IDL from "../../../../contract/groupchat_fund/target/idl/groupchat_fund.json";
 import { prisma } from "@repo/db";
-import { getFundPDA } from "./fundService";
+import { getLiveFundPDA } from "./fundService";
 
 const connection = new Connection(
   process.env.SOLANA_RPC_URL || "https://api.devnet.solana.com",
   "confirmed"
 );
 
-const programId = new PublicKey(
-  process.env.PROGRAM_ID || "JDomJJbEK48FriJ5RVuTmgDGbNN8DLKAv33NdTydcWWd"
-);
-
 /**
  * Sync fund balance from blockchain to database
  * This is called after every blockchain operation that changes balance
@@ -34,7 +30,7 @@ export async function syncFundBalance(groupId: string): Promise<number> {
     const program = new Program<GroupchatFund>(IDL as any, provider);
 
     // Get fund PDA
-    const [fundPDA] = getFundPDA(groupId, programId);
+    const fundPDA = await getLiveFundPDA(groupId, program);
 
     // ✅ STEP 1: Fetch actual balance from blockchain
     const fundAccount = await program.account.fund.fetch(fundPDA);
@@ -74,7 +70,7 @@ export async function getFundBalance(groupId: string): Promise<number> {
     );
     const program = new Program<GroupchatFund>(IDL as any, provider);
 
-    const [fundPDA] = getFundPDA(groupId, programId);
+    const fundPDA = await getLiveFundPDA(groupId, program);
     const fundAccount = await program.account.fund.fetch(fundPDA);
 
     return fundAccount.totalValue.toNumber(); // in lamports
@@ -99,7 +95,7 @@ export async function syncFullFundData(groupId: string) {
     );
     const program = new Program<GroupchatFund>(IDL as any, provider);
 
-    const [fundPDA] = getFundPDA(groupId, programId);
+    const fundPDA = await getLiveFundPDA(groupId, program);
     const fundAccount = await program.account.fund.fetch(fundPDA);
 
     // Update database with all blockchain data
@@ -107,7 +103,11 @@ export async function syncFullFundData(groupId: string) {
       where: { groupId },
       data: {
         balance: fundAccount.totalValue,
-        status: fundAccount.isActive ? "ACTIVE" : "PAUSED",
+        status: "active" in fundAccount.status
+          ? "ACTIVE"
+          : "closed" in fundAccount.status
+            ? "CLOSED"
+            : "PAUSED",
         updatedAt: new Date(),
       },
     });
@@ -117,7 +117,7 @@ export async function syncFullFundData(groupId: string) {
     return {
       balance: fundAccount.totalValue.toNumber(),
       totalShares: fundAccount.totalShares.toNumber(),
-      isActive: fundAccount.isActive,
+      status: Object.keys(fundAccount.status)[0],
     };
   } catch (error: any) {
     console.error(`❌ Full sync failed:`, error.message);
@@ -13,6 +13,7 @@ import IDL from "../../../../contract/groupchat_fund/target/idl/groupchat_fund.j
 import { prisma } from "@repo/db";
 import bs58 from "bs58";
 import { decrypt } from "../utlis";
+import { getLiveFundPDA } from "./fundService";
 
 
 const connection = new Connection(
@@ -129,15 +130,15 @@ function validateTokenAddress(token: string): PublicKey {
 // ==================== PDA HELPERS ====================
 
 /**
- * Derive fund PDA
+ * Derive the fund's mint allowlist PDA
  */
-export function getFundPDA(
-  groupId: string,
-  programId: PublicKey = new PublicKey(process.env.PROGRAM_ID!)
+export function getAllowlistPDA(
+  fundKey: PublicKey,
+  programId_: PublicKey = programId
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from("fund"), Buffer.from(groupId)],
-    programId
+    [Buffer.from("allowlist"), fundKey.toBuffer()],
+    programId_
   );
 }
 
@@ -219,7 +220,7 @@ export async function executeTrade(
     const program = getProgram(wallet);
 
     // Derive fund PDA
-    const [fundPDA] = getFundPDA(groupId, program.programId);
+    const fundPDA = await getLiveFundPDA(groupId, program);
 
     console.log("Fund PDA:", fundPDA.toString());
     console.log("Program ID:", program.programId.toString());
@@ -231,7 +232,7 @@ export async function executeTrade(
       throw new Error("Only fund authority (admin) can execute trades");
     }
 
-    if (!fundAccount.isActive) {
+    if (!("active" in fundAccount.status)) {
       throw new Error("Fund is not active");
     }
 
@@ -258,10 +259,12 @@ export async function executeTrade(
         amountBN,
         minimumOutBN
       )
-      .accounts({
+      .accountsPartial({
         fund: fundPDA,
         authority: authorityKeypair.publicKey,
         systemProgram: SystemProgram.programId,
+        allowlist: getAllowlistPDA(fundPDA, program.programId)[0],
+        proposal: null,
       })
       .signers([authorityKeypair])
       .rpc();
@@ -306,7 +309,7 @@ export async function canExecuteTrade(
     // Create a program for reading
     const program = getProgramForReading();
 
-    const [fundPDA] = getFundPDA(groupId, program.programId);
+    const fundPDA = await getLiveFundPDA(groupId, program);
     const fundAccount = await program.account.fund.fetch(fundPDA);
 
     // Check if user is the fund authority
@@ -318,7 +321,7 @@ export async function canExecuteTrade(
     }
 
     // Check if fund is active
-    if (!fundAccount.isActive) {
+    if (!("active" in fundAccount.status)) {
       return { canTrade: false, reason: "Fund is not active" };
     }
 
@@ -341,7 +344,7 @@ export async function getFundTradingInfo(groupId: string) {
   try {
     const program = getProgramForReading();
 
-    const [fundPDA] = getFundPDA(groupId, program.programId);
+    const fundPDA = await getLiveFundPDA(groupId, program);
     const fundAccount = await program.account.fund.fetch(fundPDA);
 
     // Get transactions from database
@@ -361,7 +364,7 @@ export async function getFundTradingInfo(groupId: string) {
       authority: fundAccount.authority.toBase58(),
       totalValue: fundAccount.totalValue.toNumber() / LAMPORTS_PER_SOL,
       totalShares: fundAccount.totalShares.toNumber(),
-      isActive: fundAccount.isActive,
+      status: Object.keys(fundAccount.status)[0],
       tradingFeeBps: fundAccount.tradingFeeBps,
       recentTrades: fund?.transactions || [],
     };
@@ -228,7 +228,7 @@ export function registerTradeCommands(bot: Telegraf<MyContext>) {
         msg = "❌ Admin only.\n\nCheck /fundinfo for admin details.";
       } else if (errorMsg.includes("InsufficientFunds")) {
         msg = "❌ Insufficient balance.\n\nCheck /fundinfo.";
-      } else if (errorMsg.includes("FundNotActive")) {
+      } else if (errorMsg.includes("InvalidFundStatus")) {
         msg = "❌ Fund is paused.\n\nAsk admin to resume.";
       } else {
         msg = `❌ Trade failed: ${errorMsg}`;
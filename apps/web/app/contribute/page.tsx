@@ -54,10 +54,26 @@ export default function ContributePage() {
 
       const program = new anchor.Program(idl as anchor.Idl, provider);
 
-      const [fundPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("fund"), Buffer.from(groupId)],
+      const [groupPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("group"), Buffer.from(anchor.utils.sha256.hash(groupId), "hex")],
         program.programId
       );
+      const group = await program.account.group.fetchNullable(groupPda);
+
+      // Contribute to the group's newest fund that is still open; closed
+      // funds no longer have an account
+      const funds: PublicKey[] = group ? [...group.funds].reverse() : [];
+      let fundPda: PublicKey | null = null;
+      for (const candidate of funds) {
+        const fund = await program.account.fund.fetchNullable(candidate);
+        if (fund && !("closed" in fund.status)) {
+          fundPda = candidate;
+          break;
+        }
+      }
+      if (!fundPda) {
+        throw new Error("This group has no open fund");
+      }
 
       const [memberPda] = PublicKey.findProgramAddressSync(
         [Buffer.from("member"), fundPda.toBuffer(), publicKey.toBuffer()],
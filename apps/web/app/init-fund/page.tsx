@@ -149,9 +149,27 @@ export default function InitFundPage() {
 
       const authorityPublicKey = new PublicKey(userWalletAddress);
 
-      // Derive fund PDA
+      const program = new anchor.Program(
+        idl as anchor.Idl,
+        new anchor.AnchorProvider(connection, {} as any, { commitment: "confirmed" })
+      );
+      const groupHash = Buffer.from(anchor.utils.sha256.hash(groupId), "hex");
+
+      // The new fund takes the next index in the group's registry
+      const [groupPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("group"), groupHash],
+        PROGRAM_ID
+      );
+      const group = await program.account.group.fetchNullable(groupPda);
+      const fundIndex: anchor.BN = group ? group.fundCount : new anchor.BN(0);
+
+      // Derive fund PDA: hashed group id, then the fund's index in the group
       const [fundPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("fund"), Buffer.from(groupId)],
+        [
+          Buffer.from("fund"),
+          groupHash,
+          fundIndex.toArrayLike(Buffer, "le", 8),
+        ],
         PROGRAM_ID
       );
 
@@ -1,3 +1,41 @@
+use anchor_lang::prelude::*;
+
 pub const DISCRIMINATOR: usize = 8;
 pub const MAX_STRING_LENGTH: usize = 50;
+pub const MAX_GROUP_ID_LENGTH: usize = 128;
+pub const MAX_GROUP_ADMINS: usize = 10;
 pub const BPS_DENOMINATOR: u128 = 10000;
+pub const MAX_CHECKPOINTS: usize = 16;
+pub const MAX_DESCRIPTION_LENGTH: usize = 200;
+pub const MAX_METADATA_TEXT_LENGTH: usize = 1000;
+pub const MAX_METADATA_URI_LENGTH: usize = 200;
+pub const MAX_RISK_DISCLOSURE_LENGTH: usize = 2000;
+pub const VOTING_PERIOD_SECONDS: i64 = 3 * 24 * 60 * 60;
+pub const MAX_ACTION_KINDS: usize = 16;
+pub const MAX_TIMELOCK_DELAY_SECONDS: i64 = 30 * 24 * 60 * 60;
+pub const MIN_POLICY_TIMELOCK_SECONDS: i64 = 24 * 60 * 60;
+pub const MAX_MULTISIG_SIGNERS: usize = 10;
+pub const QUORUM_BPS: u128 = 2000;
+pub const GUARDIAN_PAUSE_LOCK_SECONDS: i64 = 7 * 24 * 60 * 60;
+pub const PAUSE_REASON_AUTHORITY: u8 = 0;
+pub const MAX_TRADING_FEE_BPS: u16 = 5000;
+pub const DEFAULT_REDEMPTION_EPOCH_SECONDS: i64 = 7 * 24 * 60 * 60;
+pub const MIN_REDEMPTION_EPOCH_SECONDS: i64 = 60 * 60;
+pub const MAX_REDEMPTION_EPOCH_SECONDS: i64 = 90 * 24 * 60 * 60;
+pub const MAX_SHARE_LOTS: usize = 8;
+pub const MAX_LOCKUP_SECONDS: i64 = 365 * 24 * 60 * 60;
+pub const DEFAULT_GATE_WINDOW_SECONDS: i64 = 24 * 60 * 60;
+pub const MIN_GATE_WINDOW_SECONDS: i64 = 60 * 60;
+pub const MAX_GATE_WINDOW_SECONDS: i64 = 90 * 24 * 60 * 60;
+pub const MAX_EXIT_FEE_BPS: u16 = 1000;
+pub const MAX_DIVIDEND_LEAVES: u32 = 65_536;
+pub const MIN_DIVIDEND_CLAIM_SECONDS: i64 = 7 * 24 * 60 * 60;
+pub const REWARD_PRECISION: u128 = 1_000_000_000_000;
+pub const MAX_ALLOWED_MINTS: usize = 32;
+pub const TRADE_VOLUME_WINDOW_SECONDS: i64 = 24 * 60 * 60;
+
+/// Mints every new allowlist starts with, besides SOL: USDC and USDT.
+pub const DEFAULT_ALLOWED_MINTS: [Pubkey; 2] = [
+    pubkey!("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"),
+    pubkey!("Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB"),
+];
@@ -1,31 +1,193 @@
 use anchor_lang::prelude::*;
-use crate::state::{Fund, Member};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::errors::ErrorCode as CustomError;
+use crate::program::GroupchatFund;
+use crate::state::{DistributionRound, Group, Dividend, DividendTerms, Fund, FundAsset, FundMetadata, FundMetadataContent, MintAllowlist, Member, Multisig, MultisigTransaction, ProgramConfig, Proposal, QueuedAction, RedemptionRequest, VoteRecord};
+
+#[derive(Accounts)]
+pub struct InitializeProgramConfig<'info> {
+    #[account(
+        init,
+        payer = upgrade_authority,
+        space = ProgramConfig::SPACE,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, GroupchatFund>,
+    
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(upgrade_authority.key())
+            @ CustomError::NotUpgradeAuthority
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    
+    #[account(mut)]
+    pub upgrade_authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetRegistrar<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProgramConfig>,
+    
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, GroupchatFund>,
+    
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(upgrade_authority.key())
+            @ CustomError::NotUpgradeAuthority
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    
+    pub upgrade_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(group_id: String, group_hash: [u8; 32])]
+pub struct RegisterGroup<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = Group::space(0),
+        seeds = [b"group", group_hash.as_ref()],
+        bump
+    )]
+    pub group: Account<'info, Group>,
+    
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProgramConfig>,
+    
+    #[account(address = config.registrar @ CustomError::NotRegistrar)]
+    pub registrar: Signer<'info>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateGroup<'info> {
+    #[account(
+        mut,
+        seeds = [b"group", group.group_hash.as_ref()],
+        bump = group.bump
+    )]
+    pub group: Account<'info, Group>,
+    
+    #[account(constraint = group.is_admin(&admin.key()) @ CustomError::NotGroupAdmin)]
+    pub admin: Signer<'info>,
+}
 
 #[derive(Accounts)]
-#[instruction(group_id: String)]
 pub struct InitializeFund<'info> {
+    #[account(
+        mut,
+        seeds = [b"group", group.group_hash.as_ref()],
+        bump = group.bump,
+        realloc = Group::space(group.funds.len() + 1),
+        realloc::payer = authority,
+        realloc::zero = false
+    )]
+    pub group: Account<'info, Group>,
+    
+    #[account(constraint = group.is_admin(&group_admin.key()) @ CustomError::NotGroupAdmin)]
+    pub group_admin: Signer<'info>,
+    
     #[account(
         init,
         payer = authority,
         space = Fund::SPACE,
-        seeds = [b"fund", group_id.as_bytes()],
+        seeds = [b"fund", group.group_hash.as_ref(), &group.fund_count.to_le_bytes()],
         bump
     )]
     pub fund: Account<'info, Fund>,
     
+    #[account(
+        init,
+        payer = authority,
+        space = MintAllowlist::SPACE,
+        seeds = [b"allowlist", fund.key().as_ref()],
+        bump
+    )]
+    pub allowlist: Account<'info, MintAllowlist>,
+    
     #[account(mut)]
     pub authority: Signer<'info>,
     
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct MigrateGroup<'info> {
+    /// CHECK: still in an older layout, so it cannot be loaded as `Group`;
+    /// the discriminator and PDA are checked by the handler
+    #[account(mut, owner = crate::ID)]
+    pub group: UncheckedAccount<'info>,
+    
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProgramConfig>,
+    
+    #[account(address = config.registrar @ CustomError::NotRegistrar)]
+    pub registrar: Signer<'info>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateFund<'info> {
+    /// CHECK: still in an older layout, so it cannot be loaded as `Fund`;
+    /// the discriminator and PDA are checked by the handler
+    #[account(mut, owner = crate::ID)]
+    pub fund: UncheckedAccount<'info>,
+    
+    /// Funds from before the allowlist get theirs with the migration; newer
+    /// ones already have it
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = MintAllowlist::SPACE,
+        seeds = [b"allowlist", fund.key().as_ref()],
+        bump
+    )]
+    pub allowlist: Account<'info, MintAllowlist>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateMember<'info> {
+    #[account(mut)]
+    pub fund: Account<'info, Fund>,
+    
+    /// CHECK: still in an older layout, so it cannot be loaded as `Member`;
+    /// the discriminator and PDA are checked by the handler
+    #[account(mut, owner = crate::ID)]
+    pub member: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
 #[derive(Accounts)]
 pub struct CloseFund<'info> {
     #[account(
         mut,
         close = authority,
-        seeds = [b"fund", fund.group_id.as_bytes()],
-        bump = fund.bump
+        constraint = fund.has_address(&fund.key()) @ CustomError::InvalidFundAddress
     )]
     pub fund: Account<'info, Fund>,
     
@@ -54,6 +216,60 @@ pub struct AddMember<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct DelegateVote<'info> {
+    pub fund: Account<'info, Fund>,
+    
+    #[account(
+        mut,
+        seeds = [b"member", fund.key().as_ref(), delegator.key().as_ref()],
+        bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [b"member", fund.key().as_ref(), delegate_member.wallet.as_ref()],
+        bump
+    )]
+    pub delegate_member: Account<'info, Member>,
+    
+    pub delegator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeDelegation<'info> {
+    pub fund: Account<'info, Fund>,
+    
+    #[account(
+        mut,
+        seeds = [b"member", fund.key().as_ref(), delegator.key().as_ref()],
+        bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [b"member", fund.key().as_ref(), delegate_member.wallet.as_ref()],
+        bump,
+        constraint = member.delegate == Some(delegate_member.wallet) @ CustomError::InvalidDelegation
+    )]
+    pub delegate_member: Account<'info, Member>,
+    
+    pub delegator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct PreviewLockup<'info> {
+    pub fund: Account<'info, Fund>,
+    
+    #[account(
+        seeds = [b"member", fund.key().as_ref(), member.wallet.as_ref()],
+        bump
+    )]
+    pub member: Account<'info, Member>,
+}
+
 #[derive(Accounts)]
 pub struct Contribute<'info> {
     #[account(mut)]
@@ -72,13 +288,50 @@ pub struct Contribute<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct FinalizeFundraising<'info> {
+    #[account(mut)]
+    pub fund: Account<'info, Fund>,
+    
+    pub caller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimRefund<'info> {
+    #[account(
+        mut,
+        constraint = fund.has_address(&fund.key()) @ CustomError::InvalidFundAddress
+    )]
+    pub fund: Account<'info, Fund>,
+    
+    #[account(
+        mut,
+        seeds = [b"member", fund.key().as_ref(), member_wallet.key().as_ref()],
+        bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(mut, constraint = member_wallet.key() == member.wallet)]
+    pub member_wallet: Signer<'info>,
+}
+
 #[derive(Accounts)]
 pub struct RecordSwap<'info> {
     #[account(mut)]
     pub fund: Account<'info, Fund>,
     
-    #[account(constraint = authority.key() == fund.authority)]
+    #[account(constraint = authority.key() == fund.trader)]
     pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"allowlist", fund.key().as_ref()],
+        bump = allowlist.bump
+    )]
+    pub allowlist: Account<'info, MintAllowlist>,
+    
+    /// Passed proposal approving this trade; only needed above the limits.
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
 }
 
 #[derive(Accounts)]
@@ -90,6 +343,16 @@ pub struct ExecuteTrade<'info> {
     pub authority: Signer<'info>,
     
     pub system_program: Program<'info, System>,
+    
+    #[account(
+        seeds = [b"allowlist", fund.key().as_ref()],
+        bump = allowlist.bump
+    )]
+    pub allowlist: Account<'info, MintAllowlist>,
+    
+    /// Passed proposal approving this trade; only needed above the limits.
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
 }
 
 #[derive(Accounts)]
@@ -97,21 +360,30 @@ pub struct ExecuteJupiterSwap<'info> {
     #[account(mut)]
     pub fund: Account<'info, Fund>,
     
-    #[account(constraint = authority.key() == fund.authority)]
+    #[account(constraint = authority.key() == fund.trader)]
     pub authority: Signer<'info>,
     
     /// CHECK: Jupiter program
     pub jupiter_program: AccountInfo<'info>,
     
     pub system_program: Program<'info, System>,
+    
+    #[account(
+        seeds = [b"allowlist", fund.key().as_ref()],
+        bump = allowlist.bump
+    )]
+    pub allowlist: Account<'info, MintAllowlist>,
+    
+    /// Passed proposal approving this trade; only needed above the limits.
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
 }
 
 #[derive(Accounts)]
 pub struct DistributeValue<'info> {
     #[account(
         mut,
-        seeds = [b"fund", fund.group_id.as_bytes()],
-        bump = fund.bump
+        constraint = fund.has_address(&fund.key()) @ CustomError::InvalidFundAddress
     )]
     pub fund: Account<'info, Fund>,
     
@@ -132,8 +404,7 @@ pub struct DistributeValue<'info> {
 pub struct DistributeProfits<'info> {
     #[account(
         mut,
-        seeds = [b"fund", fund.group_id.as_bytes()],
-        bump = fund.bump
+        constraint = fund.has_address(&fund.key()) @ CustomError::InvalidFundAddress
     )]
     pub fund: Account<'info, Fund>,
     
@@ -151,41 +422,658 @@ pub struct DistributeProfits<'info> {
 }
 
 #[derive(Accounts)]
-pub struct Withdraw<'info> {
+pub struct StartDistributionRound<'info> {
+    #[account(
+        mut,
+        constraint = fund.has_address(&fund.key()) @ CustomError::InvalidFundAddress
+    )]
+    pub fund: Account<'info, Fund>,
+    
+    #[account(
+        init,
+        payer = starter,
+        space = DistributionRound::SPACE,
+        seeds = [b"round", fund.key().as_ref(), &fund.round_count.to_le_bytes()],
+        bump
+    )]
+    pub round: Account<'info, DistributionRound>,
+    
+    /// Pays for the round; must be the authority unless `proposal` or a
+    /// multisig approval is given.
+    #[account(mut)]
+    pub starter: Signer<'info>,
+    
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+    
+    /// The multisig holding the fund's authority, with the transaction
+    /// approving this call; `starter` must be one of its signers.
+    pub multisig: Option<Account<'info, Multisig>>,
+    
+    #[account(mut)]
+    pub multisig_transaction: Option<Account<'info, MultisigTransaction>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ProcessDistributionRound<'info> {
     #[account(
         mut,
-        seeds = [b"fund", fund.group_id.as_bytes()],
-        bump = fund.bump
+        constraint = fund.has_address(&fund.key()) @ CustomError::InvalidFundAddress
     )]
     pub fund: Account<'info, Fund>,
     
     #[account(
         mut,
-        seeds = [b"member", fund.key().as_ref(), member_wallet.key().as_ref()],
+        has_one = fund,
+        seeds = [b"round", fund.key().as_ref(), &round.round_id.to_le_bytes()],
+        bump = round.bump
+    )]
+    pub round: Account<'info, DistributionRound>,
+}
+
+#[derive(Accounts)]
+#[instruction(terms: DividendTerms)]
+pub struct DeclareDividend<'info> {
+    #[account(
+        mut,
+        constraint = fund.has_address(&fund.key()) @ CustomError::InvalidFundAddress
+    )]
+    pub fund: Account<'info, Fund>,
+    
+    #[account(
+        init,
+        payer = declarer,
+        space = Dividend::space(terms.leaf_count),
+        seeds = [b"dividend", fund.key().as_ref(), &fund.dividend_count.to_le_bytes()],
         bump
     )]
-    pub member: Account<'info, Member>,
+    pub dividend: Account<'info, Dividend>,
     
-    #[account(mut, constraint = member_wallet.key() == member.wallet)]
-    pub member_wallet: Signer<'info>,
+    #[account(mut)]
+    pub declarer: Signer<'info>,
+    
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+    
+    /// The multisig holding the fund's authority, with the transaction
+    /// approving this call; `declarer` must be one of its signers. Either
+    /// this or `proposal` is required.
+    pub multisig: Option<Account<'info, Multisig>>,
+    
+    #[account(mut)]
+    pub multisig_transaction: Option<Account<'info, MultisigTransaction>>,
     
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct PauseFund<'info> {
+pub struct ClaimDividend<'info> {
+    #[account(
+        mut,
+        seeds = [b"dividend", dividend.fund.as_ref(), &dividend.dividend_id.to_le_bytes()],
+        bump = dividend.bump
+    )]
+    pub dividend: Account<'info, Dividend>,
+    
     #[account(mut)]
+    pub claimant: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SweepDividend<'info> {
+    #[account(
+        mut,
+        constraint = fund.has_address(&fund.key()) @ CustomError::InvalidFundAddress
+    )]
     pub fund: Account<'info, Fund>,
     
-    #[account(constraint = authority.key() == fund.authority)]
-    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = fund,
+        has_one = declared_by,
+        close = declared_by,
+        seeds = [b"dividend", fund.key().as_ref(), &dividend.dividend_id.to_le_bytes()],
+        bump = dividend.bump
+    )]
+    pub dividend: Account<'info, Dividend>,
+    
+    /// CHECK: receives the escrow's rent; must match `dividend.declared_by`
+    #[account(mut)]
+    pub declared_by: UncheckedAccount<'info>,
 }
 
 #[derive(Accounts)]
-pub struct ResumeFund<'info> {
+pub struct CreateFundMetadata<'info> {
+    #[account(constraint = fund.has_address(&fund.key()) @ CustomError::InvalidFundAddress)]
+    pub fund: Account<'info, Fund>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = FundMetadata::space(&FundMetadataContent::default()),
+        seeds = [b"metadata", fund.key().as_ref()],
+        bump
+    )]
+    pub metadata: Account<'info, FundMetadata>,
+    
     #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(content: FundMetadataContent)]
+pub struct UpdateFundMetadata<'info> {
+    #[account(constraint = fund.has_address(&fund.key()) @ CustomError::InvalidFundAddress)]
     pub fund: Account<'info, Fund>,
     
-    #[account(constraint = authority.key() == fund.authority)]
-    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"metadata", fund.key().as_ref()],
+        bump = metadata.bump,
+        realloc = FundMetadata::space(&content),
+        realloc::payer = updater,
+        realloc::zero = false
+    )]
+    pub metadata: Account<'info, FundMetadata>,
+    
+    #[account(mut)]
+    pub updater: Signer<'info>,
+    
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+    
+    /// The multisig holding the fund's authority, with the transaction
+    /// approving this call; `updater` must be one of its signers.
+    pub multisig: Option<Account<'info, Multisig>>,
+    
+    #[account(mut)]
+    pub multisig_transaction: Option<Account<'info, MultisigTransaction>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CreateMintAllowlist<'info> {
+    #[account(constraint = fund.has_address(&fund.key()) @ CustomError::InvalidFundAddress)]
+    pub fund: Account<'info, Fund>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = MintAllowlist::SPACE,
+        seeds = [b"allowlist", fund.key().as_ref()],
+        bump
+    )]
+    pub allowlist: Account<'info, MintAllowlist>,
+    
+    #[account(mut, constraint = authority.key() == fund.authority)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetMintAllowed<'info> {
+    #[account(constraint = fund.has_address(&fund.key()) @ CustomError::InvalidFundAddress)]
+    pub fund: Account<'info, Fund>,
+    
+    #[account(
+        mut,
+        seeds = [b"allowlist", fund.key().as_ref()],
+        bump = allowlist.bump
+    )]
+    pub allowlist: Account<'info, MintAllowlist>,
+    
+    /// Passed proposal approving this change.
+    #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
+    
+    pub executor: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct DeclareRewards<'info> {
+    #[account(
+        mut,
+        constraint = fund.has_address(&fund.key()) @ CustomError::InvalidFundAddress
+    )]
+    pub fund: Account<'info, Fund>,
+    
+    #[account(constraint = authority.key() == fund.authority)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimRewards<'info> {
+    #[account(
+        mut,
+        constraint = fund.has_address(&fund.key()) @ CustomError::InvalidFundAddress
+    )]
+    pub fund: Account<'info, Fund>,
+    
+    #[account(
+        mut,
+        seeds = [b"member", fund.key().as_ref(), member_wallet.key().as_ref()],
+        bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(mut, constraint = member_wallet.key() == member.wallet)]
+    pub member_wallet: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(
+        mut,
+        constraint = fund.has_address(&fund.key()) @ CustomError::InvalidFundAddress
+    )]
+    pub fund: Account<'info, Fund>,
+    
+    #[account(
+        mut,
+        seeds = [b"member", fund.key().as_ref(), member_wallet.key().as_ref()],
+        bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(mut, constraint = member_wallet.key() == member.wallet)]
+    pub member_wallet: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RequestWithdrawal<'info> {
+    #[account(mut)]
+    pub fund: Account<'info, Fund>,
+    
+    #[account(
+        mut,
+        seeds = [b"member", fund.key().as_ref(), member_wallet.key().as_ref()],
+        bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        init,
+        payer = member_wallet,
+        space = RedemptionRequest::SPACE,
+        seeds = [b"redemption", fund.key().as_ref(), member_wallet.key().as_ref()],
+        bump
+    )]
+    pub request: Account<'info, RedemptionRequest>,
+    
+    #[account(mut)]
+    pub member_wallet: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelWithdrawal<'info> {
+    #[account(mut)]
+    pub fund: Account<'info, Fund>,
+    
+    #[account(
+        mut,
+        seeds = [b"member", fund.key().as_ref(), member_wallet.key().as_ref()],
+        bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        has_one = fund,
+        close = member_wallet,
+        seeds = [b"redemption", fund.key().as_ref(), member_wallet.key().as_ref()],
+        bump = request.bump
+    )]
+    pub request: Account<'info, RedemptionRequest>,
+    
+    #[account(mut)]
+    pub member_wallet: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ProcessRedemptions<'info> {
+    #[account(
+        mut,
+        constraint = fund.has_address(&fund.key()) @ CustomError::InvalidFundAddress
+    )]
+    pub fund: Account<'info, Fund>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterFundAsset<'info> {
+    #[account(mut)]
+    pub fund: Account<'info, Fund>,
+    
+    #[account(
+        init,
+        payer = trader,
+        space = FundAsset::SPACE,
+        seeds = [b"asset", fund.key().as_ref(), mint.key().as_ref()],
+        bump
+    )]
+    pub fund_asset: Account<'info, FundAsset>,
+    
+    pub mint: Account<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = trader,
+        token::mint = mint,
+        token::authority = fund,
+        seeds = [b"asset_vault", fund.key().as_ref(), mint.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    
+    #[account(mut, constraint = trader.key() == fund.trader @ CustomError::UnauthorizedTrader)]
+    pub trader: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RedeemInKind<'info> {
+    #[account(
+        mut,
+        constraint = fund.has_address(&fund.key()) @ CustomError::InvalidFundAddress
+    )]
+    pub fund: Account<'info, Fund>,
+    
+    #[account(
+        mut,
+        seeds = [b"member", fund.key().as_ref(), member_wallet.key().as_ref()],
+        bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(mut, constraint = member_wallet.key() == member.wallet)]
+    pub member_wallet: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PauseFund<'info> {
+    #[account(mut)]
+    pub fund: Account<'info, Fund>,
+    
+    #[account(constraint = authority.key() == fund.authority)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ResumeFund<'info> {
+    #[account(mut)]
+    pub fund: Account<'info, Fund>,
+    
+    #[account(constraint = authority.key() == fund.authority)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct GuardianPause<'info> {
+    #[account(mut)]
+    pub fund: Account<'info, Fund>,
+    
+    #[account(
+        constraint = fund.guardian == Some(guardian.key()) @ CustomError::NotGuardian
+    )]
+    pub guardian: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateFundConfig<'info> {
+    #[account(mut)]
+    pub fund: Account<'info, Fund>,
+    
+    #[account(constraint = authority.key() == fund.authority)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetTradeLimits<'info> {
+    #[account(mut)]
+    pub fund: Account<'info, Fund>,
+    
+    #[account(constraint = authority.key() == fund.authority)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    #[account(mut)]
+    pub fund: Account<'info, Fund>,
+    
+    #[account(constraint = authority.key() == fund.authority)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(mut)]
+    pub fund: Account<'info, Fund>,
+    
+    #[account(
+        constraint = fund.pending_authority == Some(new_authority.key())
+            @ CustomError::NotPendingAuthority
+    )]
+    pub new_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelAuthorityTransfer<'info> {
+    #[account(mut)]
+    pub fund: Account<'info, Fund>,
+    
+    #[account(constraint = authority.key() == fund.authority)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RenounceAuthority<'info> {
+    #[account(mut)]
+    pub fund: Account<'info, Fund>,
+    
+    #[account(constraint = authority.key() == fund.authority)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct BeginWindDown<'info> {
+    #[account(mut)]
+    pub fund: Account<'info, Fund>,
+    
+    #[account(constraint = authority.key() == fund.authority)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct QueueAction<'info> {
+    #[account(mut)]
+    pub fund: Account<'info, Fund>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = QueuedAction::SPACE,
+        seeds = [b"action", fund.key().as_ref(), &fund.action_count.to_le_bytes()],
+        bump
+    )]
+    pub queued_action: Account<'info, QueuedAction>,
+    
+    #[account(mut, constraint = authority.key() == fund.authority)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteAction<'info> {
+    #[account(mut)]
+    pub fund: Account<'info, Fund>,
+    
+    #[account(mut, has_one = fund, close = authority)]
+    pub queued_action: Account<'info, QueuedAction>,
+    
+    #[account(mut, constraint = authority.key() == fund.authority)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelAction<'info> {
+    pub fund: Account<'info, Fund>,
+    
+    #[account(mut, has_one = fund, close = authority)]
+    pub queued_action: Account<'info, QueuedAction>,
+    
+    #[account(mut, constraint = authority.key() == fund.authority)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CreateMultisig<'info> {
+    #[account(mut)]
+    pub fund: Account<'info, Fund>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = Multisig::SPACE,
+        seeds = [b"multisig", fund.key().as_ref()],
+        bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+    
+    #[account(mut, constraint = authority.key() == fund.authority)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeMultisigTransaction<'info> {
+    pub fund: Account<'info, Fund>,
+    
+    #[account(mut, has_one = fund)]
+    pub multisig: Account<'info, Multisig>,
+    
+    #[account(
+        init,
+        payer = proposer,
+        space = MultisigTransaction::SPACE,
+        seeds = [b"multisig_tx", multisig.key().as_ref(), &multisig.transaction_count.to_le_bytes()],
+        bump
+    )]
+    pub transaction: Account<'info, MultisigTransaction>,
+    
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveMultisigTransaction<'info> {
+    pub multisig: Account<'info, Multisig>,
+    
+    #[account(mut, has_one = multisig)]
+    pub transaction: Account<'info, MultisigTransaction>,
+    
+    pub signer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteMultisigTransaction<'info> {
+    #[account(mut, constraint = fund.authority == multisig.key())]
+    pub fund: Account<'info, Fund>,
+    
+    #[account(mut, has_one = fund)]
+    pub multisig: Account<'info, Multisig>,
+    
+    #[account(mut, has_one = multisig)]
+    pub transaction: Account<'info, MultisigTransaction>,
+    
+    #[account(mut)]
+    pub executor: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(mut)]
+    pub fund: Account<'info, Fund>,
+    
+    #[account(
+        seeds = [b"member", fund.key().as_ref(), proposer.key().as_ref()],
+        bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        init,
+        payer = proposer,
+        space = Proposal::SPACE,
+        seeds = [b"proposal", fund.key().as_ref(), &fund.proposal_count.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+    
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CastVote<'info> {
+    pub fund: Account<'info, Fund>,
+    
+    #[account(mut, has_one = fund)]
+    pub proposal: Account<'info, Proposal>,
+    
+    #[account(
+        seeds = [b"member", fund.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        init,
+        payer = voter,
+        space = VoteRecord::SPACE,
+        seeds = [b"vote", proposal.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(mut)]
+    pub fund: Account<'info, Fund>,
+    
+    #[account(mut, has_one = fund)]
+    pub proposal: Account<'info, Proposal>,
+    
+    /// CHECK: receives the fund's rent if the proposal closes it
+    #[account(mut, address = proposal.proposer)]
+    pub proposer: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub executor: Signer<'info>,
 }
\ No newline at end of file
@@ -3,6 +3,8 @@ use anchor_lang::prelude::*;
 
 #[error_code]
 pub enum ErrorCode {
+    /// No longer raised; status checks report `InvalidFundStatus`. Kept so
+    /// the codes after it stay stable for clients.
     #[msg("Fund is not active")]
     FundNotActive,
     
@@ -12,7 +14,7 @@ pub enum ErrorCode {
     #[msg("Contribution below minimum required")]
     BelowMinContribution,
     
-    #[msg("Only fund authority can execute trades")]
+    #[msg("Only the fund trader can execute trades")]
     UnauthorizedTrader,
     
     #[msg("Insufficient funds in vault")]
@@ -35,4 +37,229 @@ pub enum ErrorCode {
     
     #[msg("No profit available to distribute")]
     NoProfit,
+    
+    #[msg("Voting period has ended")]
+    VotingClosed,
+    
+    #[msg("Member held no shares when the proposal was created")]
+    NoVotingPower,
+    
+    #[msg("Action is timelocked and must be queued")]
+    TimelockRequired,
+    
+    #[msg("Timelock delay has not elapsed yet")]
+    TimelockNotElapsed,
+    
+    #[msg("Timelock delay out of range")]
+    InvalidTimelockDelay,
+    
+    #[msg("Signer is not part of the multisig")]
+    NotMultisigSigner,
+    
+    #[msg("Invalid multisig signer set or threshold")]
+    InvalidMultisigConfig,
+    
+    #[msg("Multisig transaction has not reached its threshold")]
+    NotEnoughApprovals,
+    
+    #[msg("Multisig transaction is stale or already executed")]
+    MultisigTransactionInvalid,
+    
+    #[msg("No authority transfer is pending")]
+    NoPendingAuthority,
+    
+    #[msg("Signer is not the pending authority")]
+    NotPendingAuthority,
+    
+    #[msg("Proposal did not pass")]
+    ProposalNotPassed,
+    
+    #[msg("Proposal has already been executed")]
+    ProposalAlreadyExecuted,
+    
+    #[msg("Proposal has no action to execute")]
+    ProposalHasNoAction,
+    
+    #[msg("Only the fund guardian can do this")]
+    NotGuardian,
+    
+    #[msg("Guardian pause can only be lifted by governance until its lock expires")]
+    GuardianPauseActive,
+    
+    #[msg("Invalid vote delegation")]
+    InvalidDelegation,
+    
+    #[msg("Member has already delegated their vote")]
+    AlreadyDelegated,
+    
+    #[msg("Config policy does not allow this change from this path")]
+    ConfigUpdateNotAllowed,
+    
+    #[msg("Config value out of bounds")]
+    InvalidConfigValue,
+    
+    #[msg("Redemption epoch is being settled")]
+    RedemptionsSettling,
+    
+    #[msg("Redemption epoch has not ended yet")]
+    EpochNotEnded,
+    
+    #[msg("Redemption request cutoff has passed")]
+    RedemptionCutoffPassed,
+    
+    #[msg("Invalid redemption request")]
+    InvalidRedemptionRequest,
+    
+    #[msg("Shares are locked or queued for redemption")]
+    SharesLocked,
+    
+    #[msg("Withdrawal gate for this window is exhausted")]
+    WithdrawalGateReached,
+    
+    #[msg("Every fund asset must be passed exactly once")]
+    InvalidAssetAccounts,
+    
+    #[msg("Proposal does not approve this action")]
+    WrongProposalAction,
+    
+    #[msg("Requires the fund authority, a passed proposal or a multisig approval")]
+    NotAuthorityOrApproved,
+    
+    #[msg("A distribution round is already in progress")]
+    DistributionRoundActive,
+    
+    #[msg("Distribution round is already complete")]
+    DistributionRoundCompleted,
+    
+    #[msg("Invalid member or wallet account for this distribution round")]
+    InvalidDistributionAccounts,
+    
+    #[msg("Invalid dividend terms")]
+    InvalidDividend,
+    
+    #[msg("Merkle proof does not match the dividend root")]
+    InvalidMerkleProof,
+    
+    #[msg("Dividend already claimed")]
+    DividendAlreadyClaimed,
+    
+    #[msg("Dividend claim deadline has passed")]
+    ClaimDeadlinePassed,
+    
+    #[msg("Dividend is still open for claims")]
+    ClaimWindowOpen,
+    
+    #[msg("No rewards available")]
+    NoRewards,
+    
+    #[msg("Fund is winding down")]
+    FundWindingDown,
+    
+    #[msg("Fund is not winding down")]
+    FundNotWindingDown,
+    
+    #[msg("Only trades back into SOL are allowed while winding down")]
+    LiquidationOnly,
+    
+    #[msg("Not allowed in the fund's current status")]
+    InvalidFundStatus,
+    
+    #[msg("Fund cannot move to that status")]
+    InvalidStatusTransition,
+    
+    #[msg("Invalid fundraising target, hard cap or deadline")]
+    InvalidFundraisingParams,
+    
+    #[msg("Fundraising deadline has passed")]
+    FundraisingClosed,
+    
+    #[msg("Contribution would exceed the fundraising hard cap")]
+    HardCapExceeded,
+    
+    #[msg("Fundraising target has not been met")]
+    FundraisingTargetNotMet,
+    
+    #[msg("Fundraising is still open")]
+    FundraisingStillOpen,
+    
+    #[msg("Refunds are only available after a failed raise")]
+    RefundsNotAvailable,
+    
+    #[msg("Account is already on the current layout")]
+    AlreadyMigrated,
+    
+    #[msg("Account layout is not recognised")]
+    UnknownAccountLayout,
+    
+    #[msg("Fund account is not at its expected address")]
+    InvalidFundAddress,
+    
+    #[msg("Group id must be between 1 and 128 bytes")]
+    InvalidGroupId,
+    
+    #[msg("Signer is not an admin of the group")]
+    NotGroupAdmin,
+    
+    #[msg("Invalid group admin list")]
+    InvalidGroupAdmins,
+    
+    #[msg("Fund metadata exceeds its length limits")]
+    MetadataTooLong,
+    
+    #[msg("Name must not be empty")]
+    EmptyName,
+    
+    #[msg("Name exceeds 50 bytes")]
+    NameTooLong,
+    
+    #[msg("Telegram id must not be empty")]
+    EmptyTelegramId,
+    
+    #[msg("Telegram id exceeds 50 bytes")]
+    TelegramIdTooLong,
+    
+    #[msg("Description exceeds 200 bytes")]
+    DescriptionTooLong,
+    
+    #[msg("Fee is above the allowed maximum")]
+    InvalidFeeBps,
+    
+    #[msg("Amount must be greater than zero")]
+    ZeroAmount,
+    
+    #[msg("Token mint is not on the fund's allowlist")]
+    MintNotAllowed,
+    
+    #[msg("Mint allowlist is full")]
+    AllowlistFull,
+    
+    #[msg("Trade exceeds the fund's trade limits and needs an approved proposal")]
+    TradeLimitExceeded,
+    
+    #[msg("Governance cannot run this action while the fund has an authority")]
+    GovernanceActionNotAllowed,
+    
+    #[msg("Signer is not the program's upgrade authority")]
+    NotUpgradeAuthority,
+    
+    #[msg("Signer is not the group registrar")]
+    NotRegistrar,
+    
+    #[msg("Loosening trade limits needs a passed proposal or a timelocked action")]
+    TradeLimitLooseningNotAllowed,
+    
+    #[msg("Members still have unclaimed rewards in the fund")]
+    RewardsUnclaimed,
+    
+    #[msg("Multisig approvals are used by the instruction they approve")]
+    MultisigApprovalNotExecutable,
+    
+    #[msg("Dividend escrows must be swept before the fund closes")]
+    DividendsUnswept,
+    
+    #[msg("Pass every dividend escrow of the fund, in order")]
+    DividendEscrowMismatch,
+    
+    #[msg("Requires a passed proposal or a multisig approval")]
+    ApprovalRequired,
 }
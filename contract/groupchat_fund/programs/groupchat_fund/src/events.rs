@@ -0,0 +1,292 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    ActionKind, ConfigPolicy, DistributionKind, DividendTerms, FundAction, FundConfig,
+    FundStatus, GroupPolicies, ProposalAction,
+};
+
+#[event]
+pub struct ActionQueued {
+    pub fund: Pubkey,
+    pub action_id: u64,
+    pub action: FundAction,
+    pub eta: i64,
+}
+
+#[event]
+pub struct ActionExecuted {
+    pub fund: Pubkey,
+    pub action_id: u64,
+    pub kind: ActionKind,
+}
+
+#[event]
+pub struct ActionCancelled {
+    pub fund: Pubkey,
+    pub action_id: u64,
+    pub kind: ActionKind,
+}
+
+#[event]
+pub struct MultisigTransactionExecuted {
+    pub fund: Pubkey,
+    pub multisig: Pubkey,
+    pub transaction_id: u64,
+}
+
+#[event]
+pub struct ProposalExecuted {
+    pub fund: Pubkey,
+    pub proposal_id: u64,
+    pub action: ProposalAction,
+}
+
+#[event]
+pub struct AuthorityTransferProposed {
+    pub fund: Pubkey,
+    pub current_authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityTransferCancelled {
+    pub fund: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub fund: Pubkey,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityRenounced {
+    pub fund: Pubkey,
+    pub previous_authority: Pubkey,
+}
+
+#[event]
+pub struct FundPaused {
+    pub fund: Pubkey,
+    pub paused_by: Pubkey,
+    pub reason_code: u8,
+}
+
+#[event]
+pub struct FundResumed {
+    pub fund: Pubkey,
+}
+
+#[event]
+pub struct FundConfigUpdated {
+    pub fund: Pubkey,
+    pub before: FundConfig,
+    pub after: FundConfig,
+}
+
+#[event]
+pub struct ConfigPolicyChanged {
+    pub fund: Pubkey,
+    pub previous: ConfigPolicy,
+    pub current: ConfigPolicy,
+}
+
+#[event]
+pub struct WithdrawalRequested {
+    pub fund: Pubkey,
+    pub wallet: Pubkey,
+    pub shares: u64,
+    pub epoch: u64,
+}
+
+#[event]
+pub struct WithdrawalCancelled {
+    pub fund: Pubkey,
+    pub wallet: Pubkey,
+    pub shares: u64,
+    pub epoch: u64,
+}
+
+#[event]
+pub struct RedemptionEpochClosed {
+    pub fund: Pubkey,
+    pub epoch: u64,
+    pub closing_value: u64,
+    pub closing_shares: u64,
+    pub pending_requests: u32,
+    pub fill_bps: u16,
+}
+
+#[event]
+pub struct RedemptionSettled {
+    pub fund: Pubkey,
+    pub wallet: Pubkey,
+    pub epoch: u64,
+    pub shares: u64,
+    pub amount: u64,
+    pub carried_shares: u64,
+}
+
+#[event]
+pub struct FundAssetRegistered {
+    pub fund: Pubkey,
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+}
+
+#[event]
+pub struct RedeemedInKind {
+    pub fund: Pubkey,
+    pub wallet: Pubkey,
+    pub shares: u64,
+    pub lamports: u64,
+    pub assets: u16,
+}
+
+#[event]
+pub struct DistributionRoundStarted {
+    pub fund: Pubkey,
+    pub round_id: u64,
+    pub kind: DistributionKind,
+    pub member_count: u64,
+}
+
+#[event]
+pub struct DistributionPaid {
+    pub fund: Pubkey,
+    pub round_id: u64,
+    pub wallet: Pubkey,
+    pub shares: u64,
+    pub amount: u64,
+}
+
+#[event]
+pub struct DistributionRoundCompleted {
+    pub fund: Pubkey,
+    pub round_id: u64,
+    pub total_paid: u64,
+}
+
+#[event]
+pub struct DividendDeclared {
+    pub fund: Pubkey,
+    pub dividend_id: u64,
+    pub terms: DividendTerms,
+}
+
+#[event]
+pub struct DividendClaimed {
+    pub fund: Pubkey,
+    pub dividend_id: u64,
+    pub wallet: Pubkey,
+    pub index: u32,
+    pub amount: u64,
+}
+
+#[event]
+pub struct DividendSwept {
+    pub fund: Pubkey,
+    pub dividend_id: u64,
+    pub amount: u64,
+}
+
+#[event]
+pub struct RewardsDeclared {
+    pub fund: Pubkey,
+    pub amount: u64,
+    pub acc_reward_per_share: u128,
+}
+
+#[event]
+pub struct RewardsClaimed {
+    pub fund: Pubkey,
+    pub wallet: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct WindDownBegun {
+    pub fund: Pubkey,
+    pub total_shares: u64,
+    pub total_value: u64,
+}
+
+#[event]
+pub struct FundDustSwept {
+    pub fund: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct FundStatusChanged {
+    pub fund: Pubkey,
+    pub from: FundStatus,
+    pub to: FundStatus,
+}
+
+#[event]
+pub struct RefundClaimed {
+    pub fund: Pubkey,
+    pub wallet: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+}
+
+#[event]
+pub struct RegistrarSet {
+    pub registrar: Pubkey,
+}
+
+#[event]
+pub struct GroupRegistered {
+    pub group: Pubkey,
+    pub group_id: String,
+}
+
+#[event]
+pub struct GroupAdminsUpdated {
+    pub group: Pubkey,
+    pub admins: Vec<Pubkey>,
+}
+
+#[event]
+pub struct GroupPoliciesUpdated {
+    pub group: Pubkey,
+    pub policies: GroupPolicies,
+}
+
+#[event]
+pub struct GroupFundAdded {
+    pub group: Pubkey,
+    pub fund: Pubkey,
+    pub fund_index: u64,
+}
+
+#[event]
+pub struct FundMetadataUpdated {
+    pub fund: Pubkey,
+    pub revision: u64,
+    pub content_hash: [u8; 32],
+    pub updated_by: Pubkey,
+}
+
+#[event]
+pub struct MintAllowlistUpdated {
+    pub fund: Pubkey,
+    pub mint: Pubkey,
+    pub allowed: bool,
+}
+
+#[event]
+pub struct TradeLimitsUpdated {
+    pub fund: Pubkey,
+    pub max_trade_bps: u16,
+    pub max_daily_volume: u64,
+}
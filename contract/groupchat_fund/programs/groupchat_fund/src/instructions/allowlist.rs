@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::constants::{DEFAULT_ALLOWED_MINTS, MAX_ALLOWED_MINTS};
+use crate::contexts::*;
+use crate::errors::ErrorCode as CustomError;
+use crate::events::MintAllowlistUpdated;
+use crate::instructions::governance::consume_approval;
+use crate::state::{MintAllowlist, ProposalAction};
+
+
+/// Creates the allowlist for a fund that was migrated before allowlists
+/// were created alongside the fund. Only the authority may do this.
+pub fn create_mint_allowlist(ctx: Context<CreateMintAllowlist>) -> Result<()> {
+    init_allowlist(&mut ctx.accounts.allowlist, ctx.accounts.fund.key(), ctx.bumps.allowlist);
+    Ok(())
+}
+
+
+/// Fills a freshly created allowlist with the protocol defaults.
+pub(crate) fn init_allowlist(allowlist: &mut MintAllowlist, fund: Pubkey, bump: u8) {
+    allowlist.version = MintAllowlist::VERSION;
+    allowlist.fund = fund;
+    allowlist.mints = DEFAULT_ALLOWED_MINTS.to_vec();
+    allowlist.bump = bump;
+
+    msg!("Mint allowlist created with {} default mints", allowlist.mints.len());
+}
+
+
+/// Adds or removes one mint on behalf of a passed proposal.
+pub fn set_mint_allowed(ctx: Context<SetMintAllowed>, mint: Pubkey, allowed: bool) -> Result<()> {
+    let fund_key = ctx.accounts.fund.key();
+    consume_approval(
+        fund_key,
+        &mut ctx.accounts.proposal,
+        &ProposalAction::SetMintAllowed { mint, allowed },
+    )?;
+
+    let allowlist = &mut ctx.accounts.allowlist;
+    if allowed {
+        if !allowlist.mints.contains(&mint) {
+            require!(allowlist.mints.len() < MAX_ALLOWED_MINTS, CustomError::AllowlistFull);
+            allowlist.mints.push(mint);
+        }
+    } else {
+        allowlist.mints.retain(|allowed_mint| *allowed_mint != mint);
+    }
+
+    emit!(MintAllowlistUpdated {
+        fund: fund_key,
+        mint,
+        allowed,
+    });
+
+    msg!("Mint {} allowed: {}", mint, allowed);
+    Ok(())
+}
+
+
+/// Rejects the trade if either side is off the allowlist, logging the mint.
+pub(crate) fn check_allowed_mints(allowlist: &MintAllowlist, mints: &[Pubkey]) -> Result<()> {
+    for mint in mints {
+        if !allowlist.allows(mint) {
+            msg!("Mint {} is not on the fund's allowlist", mint);
+            return err!(CustomError::MintNotAllowed);
+        }
+    }
+    Ok(())
+}
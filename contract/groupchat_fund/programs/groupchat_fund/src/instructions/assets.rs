@@ -0,0 +1,171 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, TokenAccount, Transfer as TokenTransfer};
+use crate::constants::BPS_DENOMINATOR;
+use crate::contexts::*;
+use crate::errors::ErrorCode as CustomError;
+use crate::events::{FundAssetRegistered, RedeemedInKind};
+use crate::state::{FundAsset, FundStatus};
+use crate::validation::validate_amount;
+
+
+pub fn register_fund_asset(ctx: Context<RegisterFundAsset>) -> Result<()> {
+    let fund = &mut ctx.accounts.fund;
+    let fund_asset = &mut ctx.accounts.fund_asset;
+
+    fund.require_status(&[FundStatus::Fundraising, FundStatus::Active])?;
+
+    fund_asset.fund = fund.key();
+    fund_asset.mint = ctx.accounts.mint.key();
+    fund_asset.vault = ctx.accounts.vault.key();
+    fund_asset.version = FundAsset::VERSION;
+    fund_asset.bump = ctx.bumps.fund_asset;
+
+    fund.asset_count = fund.asset_count
+        .checked_add(1)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+
+    emit!(FundAssetRegistered {
+        fund: fund.key(),
+        mint: fund_asset.mint,
+        vault: fund_asset.vault,
+    });
+
+    msg!("Registered asset {} with vault {}", fund_asset.mint, fund_asset.vault);
+    Ok(())
+}
+
+
+/// Pays the member their pro-rata slice of the fund's SOL and of every
+/// registered asset, passed as `remaining_accounts` triples of
+/// (fund asset, fund vault, member token account). The exit fee is held
+/// back from each asset and stays with the remaining members.
+pub fn redeem_in_kind<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RedeemInKind<'info>>,
+    shares: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let fund_key = ctx.accounts.fund.key();
+    let member_wallet_key = ctx.accounts.member_wallet.key();
+
+    ctx.accounts.fund.require_status(&[FundStatus::Active, FundStatus::WindingDown])?;
+    require!(ctx.accounts.member.is_active, CustomError::MemberNotActive);
+    validate_amount(shares)?;
+    require!(
+        ctx.accounts.member.available_shares(clock.unix_timestamp) >= shares,
+        CustomError::SharesLocked
+    );
+
+    let pages = ctx.remaining_accounts.chunks_exact(3);
+    require!(
+        pages.remainder().is_empty() && pages.len() == ctx.accounts.fund.asset_count as usize,
+        CustomError::InvalidAssetAccounts
+    );
+
+    // In-kind exits need no liquidation, so the withdrawal gate does not apply.
+    let total_shares = ctx.accounts.fund.total_shares;
+    let exit_fee_bps = ctx.accounts.fund.exit_fee_bps;
+    let fund_index = ctx.accounts.fund.fund_index.to_le_bytes();
+    let bump = [ctx.accounts.fund.bump];
+    let fund_seeds = ctx.accounts.fund.signer_seeds(&fund_index, &bump);
+    let signer_seeds: &[&[&[u8]]] = &[&fund_seeds];
+
+    let mut seen: Vec<Pubkey> = Vec::with_capacity(pages.len());
+    for accounts in pages {
+        let fund_asset: Account<'info, FundAsset> = Account::try_from(&accounts[0])?;
+        let vault: Account<'info, TokenAccount> = Account::try_from(&accounts[1])?;
+        let destination: Account<'info, TokenAccount> = Account::try_from(&accounts[2])?;
+
+        require!(
+            fund_asset.fund == fund_key
+                && vault.key() == fund_asset.vault
+                && destination.mint == fund_asset.mint
+                && destination.owner == member_wallet_key
+                && !seen.contains(&fund_asset.key()),
+            CustomError::InvalidAssetAccounts
+        );
+        seen.push(fund_asset.key());
+
+        let payout = after_exit_fee(pro_rata(vault.amount, shares, total_shares)?, exit_fee_bps)?;
+        if payout > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    TokenTransfer {
+                        from: vault.to_account_info(),
+                        to: destination.to_account_info(),
+                        authority: ctx.accounts.fund.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                payout,
+            )?;
+        }
+        msg!("Asset {}: {} tokens", fund_asset.mint, payout);
+    }
+
+    // Only lamports above the rent-exempt minimum are fund capital, and the
+    // reward reserve is already owed to holders.
+    let fund_info = ctx.accounts.fund.to_account_info();
+    let rent_floor = Rent::get()?.minimum_balance(fund_info.data_len());
+    let free_lamports = fund_info
+        .lamports()
+        .saturating_sub(rent_floor)
+        .saturating_sub(ctx.accounts.fund.reward_reserve);
+    let lamports = after_exit_fee(pro_rata(free_lamports, shares, total_shares)?, exit_fee_bps)?;
+
+    **fund_info.try_borrow_mut_lamports()? -= lamports;
+    **ctx.accounts.member_wallet.to_account_info().try_borrow_mut_lamports()? += lamports;
+
+    let fund = &mut ctx.accounts.fund;
+    let member = &mut ctx.accounts.member;
+
+    let value_out = after_exit_fee(pro_rata(fund.total_value, shares, total_shares)?, exit_fee_bps)?;
+    let basis_out = pro_rata(member.total_contributed, shares, member.shares)?;
+
+    member.settle_rewards(fund.acc_reward_per_share);
+    member.shares = member.shares
+        .checked_sub(shares)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+    member.total_contributed = member.total_contributed
+        .checked_sub(basis_out)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+    member.record_checkpoint(clock.slot);
+    member.sync_reward_debt(fund.acc_reward_per_share);
+
+    fund.total_shares = fund.total_shares
+        .checked_sub(shares)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+    fund.total_value = fund.total_value
+        .checked_sub(value_out)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+
+    emit!(RedeemedInKind {
+        fund: fund_key,
+        wallet: member_wallet_key,
+        shares,
+        lamports,
+        assets: fund.asset_count,
+    });
+
+    msg!("Redeemed {} shares in kind ({} lamports)", shares, lamports);
+    Ok(())
+}
+
+
+fn pro_rata(amount: u64, shares: u64, total_shares: u64) -> Result<u64> {
+    Ok((amount as u128)
+        .checked_mul(shares as u128)
+        .ok_or(CustomError::ArithmeticOverflow)?
+        .checked_div(total_shares as u128)
+        .ok_or(CustomError::ArithmeticOverflow)? as u64)
+}
+
+
+fn after_exit_fee(amount: u64, exit_fee_bps: u16) -> Result<u64> {
+    let fee = (amount as u128)
+        .checked_mul(exit_fee_bps as u128)
+        .ok_or(CustomError::ArithmeticOverflow)?
+        .checked_div(BPS_DENOMINATOR)
+        .ok_or(CustomError::ArithmeticOverflow)? as u64;
+    Ok(amount - fee)
+}
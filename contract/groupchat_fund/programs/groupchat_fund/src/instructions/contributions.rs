@@ -2,15 +2,32 @@ use anchor_lang::prelude::*;
 use anchor_lang::system_program::{transfer, Transfer as SystemTransfer};
 use crate::contexts::*;
 use crate::errors::ErrorCode as CustomError;  // ✅ Add this import
+use crate::instructions::fund_management::transition_status;
+use crate::state::FundStatus;
+use crate::validation::validate_amount;
 
 pub fn contribute(ctx: Context<Contribute>, amount: u64) -> Result<()> {
-    require!(ctx.accounts.fund.is_active, CustomError::FundNotActive);
+    validate_amount(amount)?;
+    ctx.accounts.fund.require_status(&[FundStatus::Fundraising, FundStatus::Active])?;
     require!(ctx.accounts.member.is_active, CustomError::MemberNotActive);
     require!(
         amount >= ctx.accounts.fund.min_contribution,
         CustomError::BelowMinContribution
     );
 
+    let raising = ctx.accounts.fund.status == FundStatus::Fundraising;
+    if raising {
+        let params = ctx.accounts.fund.fundraising.ok_or(CustomError::InvalidFundStatus)?;
+        require!(
+            Clock::get()?.unix_timestamp < params.deadline,
+            CustomError::FundraisingClosed
+        );
+        let raised = ctx.accounts.fund.total_value
+            .checked_add(amount)
+            .ok_or(CustomError::ArithmeticOverflow)?;
+        require!(raised <= params.hard_cap, CustomError::HardCapExceeded);
+    }
+
     let cpi_context = CpiContext::new(
         ctx.accounts.system_program.to_account_info(),
         SystemTransfer {
@@ -33,11 +50,28 @@ pub fn contribute(ctx: Context<Contribute>, amount: u64) -> Result<()> {
             .unwrap() as u64
     };
 
+    member.settle_rewards(fund.acc_reward_per_share);
     member.shares += shares_to_mint;
     member.total_contributed += amount;
     fund.total_shares += shares_to_mint;
     fund.total_value += amount;
 
+    let clock = Clock::get()?;
+    if fund.lockup_seconds > 0 {
+        let unlock_at = clock
+            .unix_timestamp
+            .checked_add(fund.lockup_seconds)
+            .ok_or(CustomError::ArithmeticOverflow)?;
+        member.add_lot(shares_to_mint, unlock_at, clock.unix_timestamp);
+    }
+    member.record_checkpoint(clock.slot);
+    member.sync_reward_debt(fund.acc_reward_per_share);
+
+    // Hitting the hard cap ends the raise straight away.
+    if raising && fund.fundraising.is_some_and(|params| fund.total_value >= params.hard_cap) {
+        transition_status(fund, FundStatus::Active)?;
+    }
+
     msg!(
         "Contributed {} lamports, minted {} shares",
         amount,
@@ -46,12 +80,24 @@ pub fn contribute(ctx: Context<Contribute>, amount: u64) -> Result<()> {
     Ok(())
 }
 
+/// Instant exit at live NAV, only while the fund winds down. An active fund
+/// exits through the redemption queue, which settles everyone in an epoch
+/// at one price so members cannot run ahead of a known loss.
 pub fn withdraw(ctx: Context<Withdraw>, shares_to_burn: u64) -> Result<()> {
+    validate_amount(shares_to_burn)?;
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.fund.require_status(&[FundStatus::WindingDown])?;
     require!(
-        ctx.accounts.member.shares >= shares_to_burn,
-        CustomError::InsufficientShares
+        ctx.accounts.member.available_shares(now) >= shares_to_burn,
+        CustomError::SharesLocked
     );
 
+    // Above the withdrawal gate the request is only partly filled; the rest
+    // stays with the member for the next window.
+    let requested_shares = shares_to_burn;
+    let shares_to_burn = ctx.accounts.fund.gate_fillable_shares(requested_shares, now);
+    require!(shares_to_burn > 0, CustomError::WithdrawalGateReached);
+
     let withdrawal_amount = (shares_to_burn as u128)
         .checked_mul(ctx.accounts.fund.total_value as u128)
         .unwrap()
@@ -61,14 +107,25 @@ pub fn withdraw(ctx: Context<Withdraw>, shares_to_burn: u64) -> Result<()> {
     **ctx.accounts.fund.to_account_info().try_borrow_mut_lamports()? -= withdrawal_amount;
     **ctx.accounts.member_wallet.to_account_info().try_borrow_mut_lamports()? += withdrawal_amount;
 
+    let acc_reward_per_share = ctx.accounts.fund.acc_reward_per_share;
+    ctx.accounts.member.settle_rewards(acc_reward_per_share);
     ctx.accounts.member.shares -= shares_to_burn;
     ctx.accounts.fund.total_shares -= shares_to_burn;
     ctx.accounts.fund.total_value -= withdrawal_amount;
+    ctx.accounts.fund.withdrawal_gate.record(withdrawal_amount);
+    ctx.accounts.member.record_checkpoint(Clock::get()?.slot);
+    ctx.accounts.member.sync_reward_debt(acc_reward_per_share);
 
     msg!(
         "Withdrew {} lamports by burning {} shares",
         withdrawal_amount,
         shares_to_burn
     );
+    if shares_to_burn < requested_shares {
+        msg!(
+            "Withdrawal gate reached: {} shares left unfilled",
+            requested_shares - shares_to_burn
+        );
+    }
     Ok(())
 }
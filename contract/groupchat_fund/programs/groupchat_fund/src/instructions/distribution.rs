@@ -2,64 +2,46 @@ use anchor_lang::prelude::*;
 use crate::contexts::*;
 use crate::errors::ErrorCode as CustomError;  // ✅ THIS LINE
 use crate::constants::BPS_DENOMINATOR;
+use crate::events::{DistributionPaid, DistributionRoundCompleted, DistributionRoundStarted};
+use crate::instructions::governance::require_authority_or_approval;
+use crate::instructions::rewards::take_pending_rewards;
+use crate::state::{DistributionKind, DistributionRound, Fund, FundStatus, Member, ProposalAction};
 
 
+/// Pays out the member's available shares at the current NAV. Only a
+/// winding-down fund pays at live NAV; while it trades, exits go through the
+/// redemption queue so everyone leaving in an epoch gets the same price.
 pub fn distribute_value(ctx: Context<DistributeValue>) -> Result<()> {
-    let fund = &ctx.accounts.fund;
-    let member = &ctx.accounts.member;
+    let now = Clock::get()?.unix_timestamp;
 
-    require!(fund.is_active, CustomError::FundNotActive);
-    require!(member.is_active, CustomError::MemberNotActive);
-    require!(member.shares > 0, CustomError::InsufficientShares);
-    require!(fund.total_shares > 0, CustomError::SharesRemaining);
+    ctx.accounts.fund.require_status(&[FundStatus::WindingDown])?;
+    require!(ctx.accounts.member.is_active, CustomError::MemberNotActive);
+    require!(ctx.accounts.member.shares > 0, CustomError::InsufficientShares);
+    require!(ctx.accounts.fund.total_shares > 0, CustomError::SharesRemaining);
 
-    let member_current_value = (member.shares as u128)
-        .checked_mul(fund.total_value as u128)
-        .ok_or(CustomError::ArithmeticOverflow)?
-        .checked_div(fund.total_shares as u128)
-        .ok_or(CustomError::ArithmeticOverflow)? as u64;
+    // Locked and queued shares stay in the fund; only the rest is paid out,
+    // and no more than the withdrawal gate allows in this window.
+    let available_shares = ctx.accounts.member.available_shares(now);
+    require!(available_shares > 0, CustomError::SharesLocked);
+    let redeemed_shares = ctx.accounts.fund.gate_fillable_shares(available_shares, now);
+    require!(redeemed_shares > 0, CustomError::WithdrawalGateReached);
 
-    require!(member_current_value > 0, CustomError::InsufficientFunds);
+    let payout = value_payout(&ctx.accounts.fund, &ctx.accounts.member, redeemed_shares)?;
+    require!(payout.current_value > 0, CustomError::InsufficientFunds);
 
-    let member_initial_value = member.total_contributed;
-    
-    let distribution_amount = if member_current_value > member_initial_value {
-        let profit = member_current_value
-            .checked_sub(member_initial_value)
-            .ok_or(CustomError::ArithmeticOverflow)?;
-        
-        let fee_amount = (profit as u128)
-            .checked_mul(fund.trading_fee_bps as u128)
-            .ok_or(CustomError::ArithmeticOverflow)?
-            .checked_div(BPS_DENOMINATOR)
-            .ok_or(CustomError::ArithmeticOverflow)? as u64;
-        
-        member_current_value
-            .checked_sub(fee_amount)
-            .ok_or(CustomError::ArithmeticOverflow)?
-    } else {
-        member_current_value
-    };
-
-    let fund_balance = ctx.accounts.fund.to_account_info().lamports();
-    require!(fund_balance >= distribution_amount, CustomError::InsufficientFunds);
-
-    **ctx.accounts.fund.to_account_info().try_borrow_mut_lamports()? -= distribution_amount;
-    **ctx.accounts.member_wallet.to_account_info().try_borrow_mut_lamports()? += distribution_amount;
+    pay_out(
+        &ctx.accounts.fund.to_account_info(),
+        &ctx.accounts.member_wallet.to_account_info(),
+        payout.amount,
+    )?;
 
     let fund = &mut ctx.accounts.fund;
     let member = &mut ctx.accounts.member;
-    
-    fund.total_value = fund.total_value
-        .checked_sub(distribution_amount)
-        .ok_or(CustomError::ArithmeticOverflow)?;
-    
-    fund.total_shares = fund.total_shares
-        .checked_sub(member.shares)
-        .ok_or(CustomError::ArithmeticOverflow)?;
-    
-    let member_shares = member.shares;
-    member.shares = 0;
+    settle_value_payout(fund, member, &payout, Clock::get()?.slot)?;
+
+    let member_current_value = payout.current_value;
+    let member_initial_value = payout.cost_basis;
+    let distribution_amount = payout.amount;
 
     let profit_or_loss = (distribution_amount as i128) - (member_initial_value as i128);
     let status = if profit_or_loss > 0 { 
@@ -76,7 +58,8 @@ pub fn distribute_value(ctx: Context<DistributeValue>) -> Result<()> {
     msg!("VALUE DISTRIBUTED");
     msg!("========================================");
     msg!("Member: {}", member.telegram_id);
-    msg!("Shares Burned: {}", member_shares);
+    msg!("Shares Burned: {}", redeemed_shares);
+    msg!("Shares Still Locked: {}", member.shares);
     msg!("Initial Contribution: {} lamports", member_initial_value);
     msg!("Current Value: {} lamports", member_current_value);
     msg!("Trading Fee: {} lamports", fee_charged);
@@ -88,53 +71,58 @@ pub fn distribute_value(ctx: Context<DistributeValue>) -> Result<()> {
 }
 
 
+/// Pays out the member's profit and burns the shares that backed it at the
+/// current NAV, so the fund's books keep matching its assets. The member's
+/// cost basis stays with the shares they keep; the trading fee is left in the
+/// fund for the remaining holders. Like `distribute_value`, this is only open
+/// once the fund is winding down.
 pub fn distribute_profits(ctx: Context<DistributeProfits>) -> Result<()> {
+    let clock = Clock::get()?;
     let fund = &ctx.accounts.fund;
     let member = &ctx.accounts.member;
 
-    require!(fund.is_active, CustomError::FundNotActive);
+    fund.require_status(&[FundStatus::WindingDown])?;
     require!(member.is_active, CustomError::MemberNotActive);
     require!(member.shares > 0, CustomError::InsufficientShares);
     require!(fund.total_shares > 0, CustomError::SharesRemaining);
 
-    let member_current_value = (member.shares as u128)
-        .checked_mul(fund.total_value as u128)
-        .ok_or(CustomError::ArithmeticOverflow)?
-        .checked_div(fund.total_shares as u128)
-        .ok_or(CustomError::ArithmeticOverflow)? as u64;
+    let payout = profit_payout(fund, member)?.ok_or(CustomError::NoProfit)?;
 
-    let member_initial_value = member.total_contributed;
-    
     require!(
-        member_current_value > member_initial_value,
-        CustomError::NoProfit
+        payout.shares_to_burn <= member.available_shares(clock.unix_timestamp),
+        CustomError::SharesLocked
+    );
+    require!(
+        ctx.accounts.fund.gate_fillable_shares(payout.shares_to_burn, clock.unix_timestamp)
+            == payout.shares_to_burn,
+        CustomError::WithdrawalGateReached
     );
 
-    let profit_amount = member_current_value
-        .checked_sub(member_initial_value)
-        .ok_or(CustomError::ArithmeticOverflow)?;
-
-    let fee_amount = (profit_amount as u128)
-        .checked_mul(fund.trading_fee_bps as u128)
-        .ok_or(CustomError::ArithmeticOverflow)?
-        .checked_div(BPS_DENOMINATOR)
-        .ok_or(CustomError::ArithmeticOverflow)? as u64;
-
-    let net_profit = profit_amount
-        .checked_sub(fee_amount)
-        .ok_or(CustomError::ArithmeticOverflow)?;
+    pay_out(
+        &ctx.accounts.fund.to_account_info(),
+        &ctx.accounts.member_wallet.to_account_info(),
+        payout.amount,
+    )?;
 
-    let fund_balance = ctx.accounts.fund.to_account_info().lamports();
-    require!(fund_balance >= net_profit, CustomError::InsufficientFunds);
+    let fund = &mut ctx.accounts.fund;
+    let member = &mut ctx.accounts.member;
+    settle_profit_payout(fund, member, &payout, clock.slot)?;
 
-    **ctx.accounts.fund.to_account_info().try_borrow_mut_lamports()? -= net_profit;
-    **ctx.accounts.member_wallet.to_account_info().try_borrow_mut_lamports()? += net_profit;
+    let ProfitPayout {
+        current_value: member_current_value,
+        cost_basis: member_initial_value,
+        profit: profit_amount,
+        fee: fee_amount,
+        amount: net_profit,
+        shares_to_burn,
+    } = payout;
 
     msg!("========================================");
-    msg!("PROFIT DISTRIBUTED (Shares Retained)");
+    msg!("PROFIT DISTRIBUTED");
     msg!("========================================");
     msg!("Member: {}", member.telegram_id);
-    msg!("Shares: {}", member.shares);
+    msg!("Shares Burned: {}", shares_to_burn);
+    msg!("Shares Remaining: {}", member.shares);
     msg!("Member Current Value: {} lamports", member_current_value);
     msg!("Initial Contribution: {} lamports", member_initial_value);
     msg!("Gross Profit: {} lamports", profit_amount);
@@ -144,3 +132,456 @@ pub fn distribute_profits(ctx: Context<DistributeProfits>) -> Result<()> {
 
     Ok(())
 }
+
+
+/// Starts a fund-wide distribution that `process_distribution_round` pays
+/// out in pages, at the authority's call or a vote's.
+pub fn start_distribution_round(
+    ctx: Context<StartDistributionRound>,
+    kind: DistributionKind,
+) -> Result<()> {
+    let fund_key = ctx.accounts.fund.key();
+
+    require_authority_or_approval(
+        &ctx.accounts.fund,
+        ctx.accounts.starter.key(),
+        ctx.accounts.proposal.as_mut(),
+        ctx.accounts.multisig.as_ref().zip(ctx.accounts.multisig_transaction.as_mut()),
+        &ProposalAction::StartDistributionRound { kind },
+    )?;
+
+    let fund = &mut ctx.accounts.fund;
+    fund.require_status(&[FundStatus::Active, FundStatus::WindingDown])?;
+    require!(fund.active_round.is_none(), CustomError::DistributionRoundActive);
+    // A wound-down fund only pays out in full, and only once the redemption
+    // queue is empty so no request is left pointing at burned shares.
+    if fund.status == FundStatus::WindingDown {
+        require!(kind == DistributionKind::Final, CustomError::FundWindingDown);
+        require!(
+            fund.redemptions.pending_requests == 0 && !fund.redemptions.settling,
+            CustomError::RedemptionsSettling
+        );
+    } else {
+        require!(kind != DistributionKind::Final, CustomError::FundNotWindingDown);
+    }
+
+    let round = &mut ctx.accounts.round;
+    round.fund = fund_key;
+    round.round_id = fund.round_count;
+    round.kind = kind;
+    round.started_at = Clock::get()?.unix_timestamp;
+    round.member_count = fund.member_count;
+    round.processed = 0;
+    round.total_paid = 0;
+    round.completed = fund.member_count == 0;
+    round.version = DistributionRound::VERSION;
+    round.bump = ctx.bumps.round;
+
+    fund.round_count = fund.round_count
+        .checked_add(1)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+    if !round.completed {
+        fund.active_round = Some(round.round_id);
+    }
+
+    emit!(DistributionRoundStarted {
+        fund: fund_key,
+        round_id: round.round_id,
+        kind,
+        member_count: round.member_count,
+    });
+
+    msg!("Distribution round {} started for {} members", round.round_id, round.member_count);
+    Ok(())
+}
+
+
+/// Pays a page of members passed as `remaining_accounts` pairs of
+/// (member, member wallet). Anyone may crank. Members already paid in this
+/// round, or registered after it started, are skipped, so pages can be
+/// retried safely. Outside a final round, locked shares and anything above
+/// the withdrawal gate stay unpaid this round.
+pub fn process_distribution_round<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ProcessDistributionRound<'info>>,
+) -> Result<()> {
+    let fund_key = ctx.accounts.fund.key();
+    let clock = Clock::get()?;
+    let round_id = ctx.accounts.round.round_id;
+    let kind = ctx.accounts.round.kind;
+
+    ctx.accounts.fund.require_status(&[FundStatus::Active, FundStatus::WindingDown])?;
+    require!(!ctx.accounts.round.completed, CustomError::DistributionRoundCompleted);
+
+    let pages = ctx.remaining_accounts.chunks_exact(2);
+    require!(pages.remainder().is_empty(), CustomError::InvalidDistributionAccounts);
+
+    for accounts in pages {
+        let mut member: Account<'info, Member> = Account::try_from(&accounts[0])?;
+        let member_wallet = &accounts[1];
+
+        let (expected_member, _) = Pubkey::find_program_address(
+            &[b"member", fund_key.as_ref(), member.wallet.as_ref()],
+            ctx.program_id,
+        );
+        require!(
+            member.key() == expected_member && member_wallet.key() == member.wallet,
+            CustomError::InvalidDistributionAccounts
+        );
+
+        if member.last_paid_round == Some(round_id) || member.joined_round > round_id {
+            continue;
+        }
+
+        let fund = &mut ctx.accounts.fund;
+        let available_shares = member.available_shares(clock.unix_timestamp);
+        let (shares, amount) = match kind {
+            DistributionKind::Final => {
+                let mut shares = 0;
+                let mut amount = 0;
+                if member.shares > 0 {
+                    let payout = value_payout(fund, &member, member.shares)?;
+                    settle_value_payout(fund, &mut member, &payout, clock.slot)?;
+                    shares = payout.shares;
+                    amount = payout.amount;
+                }
+                amount += take_pending_rewards(fund, &mut member)?;
+                pay_out(&fund.to_account_info(), member_wallet, amount)?;
+                (shares, amount)
+            }
+            _ if !member.is_active => (0, 0),
+            DistributionKind::Value if available_shares > 0 => {
+                // Above the withdrawal gate the rest stays unpaid this round.
+                let shares = fund.gate_fillable_shares(available_shares, clock.unix_timestamp);
+                if shares > 0 {
+                    let payout = value_payout(fund, &member, shares)?;
+                    pay_out(&fund.to_account_info(), member_wallet, payout.amount)?;
+                    settle_value_payout(fund, &mut member, &payout, clock.slot)?;
+                    (payout.shares, payout.amount)
+                } else {
+                    (0, 0)
+                }
+            }
+            DistributionKind::Profits => match profit_payout(fund, &member)? {
+                Some(payout)
+                    if payout.shares_to_burn <= available_shares
+                        && fund.gate_fillable_shares(payout.shares_to_burn, clock.unix_timestamp)
+                            == payout.shares_to_burn =>
+                {
+                    pay_out(&fund.to_account_info(), member_wallet, payout.amount)?;
+                    settle_profit_payout(fund, &mut member, &payout, clock.slot)?;
+                    (payout.shares_to_burn, payout.amount)
+                }
+                _ => (0, 0),
+            },
+            _ => (0, 0),
+        };
+
+        member.last_paid_round = Some(round_id);
+        member.exit(ctx.program_id)?;
+
+        let round = &mut ctx.accounts.round;
+        round.processed = round.processed
+            .checked_add(1)
+            .ok_or(CustomError::ArithmeticOverflow)?;
+        round.total_paid = round.total_paid
+            .checked_add(amount)
+            .ok_or(CustomError::ArithmeticOverflow)?;
+
+        if amount > 0 {
+            emit!(DistributionPaid {
+                fund: fund_key,
+                round_id,
+                wallet: member.wallet,
+                shares,
+                amount,
+            });
+        }
+    }
+
+    let round = &mut ctx.accounts.round;
+    if round.processed >= round.member_count {
+        round.completed = true;
+        ctx.accounts.fund.active_round = None;
+
+        emit!(DistributionRoundCompleted {
+            fund: fund_key,
+            round_id,
+            total_paid: round.total_paid,
+        });
+        msg!("Distribution round {} complete: {} lamports paid", round_id, round.total_paid);
+    } else {
+        msg!(
+            "Distribution round {}: {} of {} members processed",
+            round_id,
+            round.processed,
+            round.member_count
+        );
+    }
+
+    Ok(())
+}
+
+
+/// What a member receives for redeeming `shares` at the current NAV: their
+/// value less the trading fee on any gain over the slice's cost basis.
+pub(crate) struct ValuePayout {
+    pub shares: u64,
+    pub current_value: u64,
+    pub cost_basis: u64,
+    pub amount: u64,
+}
+
+pub(crate) fn value_payout(fund: &Fund, member: &Member, shares: u64) -> Result<ValuePayout> {
+    let current_value = (shares as u128)
+        .checked_mul(fund.total_value as u128)
+        .ok_or(CustomError::ArithmeticOverflow)?
+        .checked_div(fund.total_shares as u128)
+        .ok_or(CustomError::ArithmeticOverflow)? as u64;
+
+    // Cost basis of the redeemed slice.
+    let cost_basis = (member.total_contributed as u128)
+        .checked_mul(shares as u128)
+        .ok_or(CustomError::ArithmeticOverflow)?
+        .checked_div(member.shares as u128)
+        .ok_or(CustomError::ArithmeticOverflow)? as u64;
+
+    let amount = if current_value > cost_basis {
+        let fee = trading_fee(fund, current_value - cost_basis)?;
+        current_value
+            .checked_sub(fee)
+            .ok_or(CustomError::ArithmeticOverflow)?
+    } else {
+        current_value
+    };
+
+    Ok(ValuePayout { shares, current_value, cost_basis, amount })
+}
+
+/// Burns the redeemed shares and takes their cost basis off the member.
+pub(crate) fn settle_value_payout(
+    fund: &mut Fund,
+    member: &mut Member,
+    payout: &ValuePayout,
+    slot: u64,
+) -> Result<()> {
+    fund.total_value = fund.total_value
+        .checked_sub(payout.amount)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+    fund.total_shares = fund.total_shares
+        .checked_sub(payout.shares)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+    fund.withdrawal_gate.record(payout.amount);
+
+    member.settle_rewards(fund.acc_reward_per_share);
+    member.shares = member.shares
+        .checked_sub(payout.shares)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+    member.total_contributed = member.total_contributed
+        .checked_sub(payout.cost_basis)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+    member.record_checkpoint(slot);
+    member.sync_reward_debt(fund.acc_reward_per_share);
+    Ok(())
+}
+
+/// A member's profit over cost basis, net of the trading fee, and the shares
+/// worth the gross profit at the current NAV.
+pub(crate) struct ProfitPayout {
+    pub current_value: u64,
+    pub cost_basis: u64,
+    pub profit: u64,
+    pub fee: u64,
+    pub amount: u64,
+    pub shares_to_burn: u64,
+}
+
+/// `None` when the member holds nothing or is not in profit.
+pub(crate) fn profit_payout(fund: &Fund, member: &Member) -> Result<Option<ProfitPayout>> {
+    if member.shares == 0 || fund.total_shares == 0 {
+        return Ok(None);
+    }
+
+    let current_value = (member.shares as u128)
+        .checked_mul(fund.total_value as u128)
+        .ok_or(CustomError::ArithmeticOverflow)?
+        .checked_div(fund.total_shares as u128)
+        .ok_or(CustomError::ArithmeticOverflow)? as u64;
+
+    let cost_basis = member.total_contributed;
+    if current_value <= cost_basis {
+        return Ok(None);
+    }
+
+    let profit = current_value - cost_basis;
+    let fee = trading_fee(fund, profit)?;
+    let amount = profit
+        .checked_sub(fee)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+
+    // Rounded up so rounding never favours the member over the rest of
+    // the fund.
+    let shares_to_burn = (profit as u128)
+        .checked_mul(fund.total_shares as u128)
+        .ok_or(CustomError::ArithmeticOverflow)?
+        .checked_add(fund.total_value as u128 - 1)
+        .ok_or(CustomError::ArithmeticOverflow)?
+        .checked_div(fund.total_value as u128)
+        .ok_or(CustomError::ArithmeticOverflow)? as u64;
+
+    Ok(Some(ProfitPayout { current_value, cost_basis, profit, fee, amount, shares_to_burn }))
+}
+
+/// Burns the shares backing the paid profit. The member's cost basis stays
+/// with the shares they keep.
+pub(crate) fn settle_profit_payout(
+    fund: &mut Fund,
+    member: &mut Member,
+    payout: &ProfitPayout,
+    slot: u64,
+) -> Result<()> {
+    fund.total_value = fund.total_value
+        .checked_sub(payout.amount)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+    fund.total_shares = fund.total_shares
+        .checked_sub(payout.shares_to_burn)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+    fund.withdrawal_gate.record(payout.amount);
+
+    member.settle_rewards(fund.acc_reward_per_share);
+    member.shares = member.shares
+        .checked_sub(payout.shares_to_burn)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+    member.record_checkpoint(slot);
+    member.sync_reward_debt(fund.acc_reward_per_share);
+    Ok(())
+}
+
+fn trading_fee(fund: &Fund, profit: u64) -> Result<u64> {
+    Ok((profit as u128)
+        .checked_mul(fund.trading_fee_bps as u128)
+        .ok_or(CustomError::ArithmeticOverflow)?
+        .checked_div(BPS_DENOMINATOR)
+        .ok_or(CustomError::ArithmeticOverflow)? as u64)
+}
+
+fn pay_out(fund: &AccountInfo, wallet: &AccountInfo, amount: u64) -> Result<()> {
+    require!(fund.lamports() >= amount, CustomError::InsufficientFunds);
+    **fund.try_borrow_mut_lamports()? -= amount;
+    **wallet.try_borrow_mut_lamports()? += amount;
+    Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{fund, member};
+
+    /// NAV per share after must not be below NAV per share before.
+    fn assert_nav_held(before: (u64, u64), fund: &Fund) {
+        let (value, shares) = before;
+        assert!(
+            (fund.total_value as u128) * (shares as u128)
+                >= (value as u128) * (fund.total_shares as u128),
+            "NAV per share fell from {}/{} to {}/{}",
+            value,
+            shares,
+            fund.total_value,
+            fund.total_shares
+        );
+    }
+
+    fn holding(shares: u64, contributed: u64) -> Member {
+        let mut member = member();
+        member.shares = shares;
+        member.total_contributed = contributed;
+        member
+    }
+
+    #[test]
+    fn profit_payout_rounds_burned_shares_up() {
+        let mut fund = fund();
+        fund.total_value = 7;
+        fund.total_shares = 3;
+        let mut member = holding(2, 3);
+
+        // 2 shares are worth 4, so the profit of 1 is worth 3/7 of a share.
+        let payout = profit_payout(&fund, &member).unwrap().unwrap();
+        assert_eq!(payout.profit, 1);
+        assert_eq!(payout.shares_to_burn, 1);
+
+        let before = (fund.total_value, fund.total_shares);
+        settle_profit_payout(&mut fund, &mut member, &payout, 1).unwrap();
+        assert_nav_held(before, &fund);
+        assert_eq!(member.shares, 1);
+    }
+
+    #[test]
+    fn profit_payout_never_burns_more_than_held() {
+        let mut fund = fund();
+        fund.total_value = 1_000_003;
+        fund.total_shares = 999_999;
+        let member = holding(1, 0);
+
+        let payout = profit_payout(&fund, &member).unwrap().unwrap();
+        assert!(payout.shares_to_burn <= member.shares);
+    }
+
+    #[test]
+    fn no_payout_without_profit() {
+        let mut fund = fund();
+        fund.total_value = 1_000;
+        fund.total_shares = 1_000;
+        let member = holding(100, 100);
+
+        assert!(profit_payout(&fund, &member).unwrap().is_none());
+    }
+
+    #[test]
+    fn repeated_profit_payouts_keep_fund_solvent() {
+        for trading_fee_bps in [0, 250, 2_000] {
+            let mut fund = fund();
+            fund.trading_fee_bps = trading_fee_bps;
+            let mut members = [
+                holding(1_000_003, 1_000_003),
+                holding(333_333, 333_333),
+                holding(7, 7),
+            ];
+            fund.total_shares = members.iter().map(|member| member.shares).sum();
+            fund.total_value = fund.total_shares;
+            let mut paid: u64 = 0;
+
+            for round in 1..=20u64 {
+                // Odd gains so NAV per share is rarely a whole number.
+                fund.total_value += fund.total_value / 7 + 13;
+
+                for member in members.iter_mut() {
+                    let Some(payout) = profit_payout(&fund, member).unwrap() else {
+                        continue;
+                    };
+                    assert!(payout.shares_to_burn <= member.shares);
+
+                    let before = (fund.total_value, fund.total_shares);
+                    settle_profit_payout(&mut fund, member, &payout, round).unwrap();
+                    assert_nav_held(before, &fund);
+                    paid += payout.amount;
+                }
+
+                // What every remaining holder could redeem is still covered.
+                let owed: u128 = members
+                    .iter()
+                    .map(|member| {
+                        (member.shares as u128) * (fund.total_value as u128)
+                            / (fund.total_shares as u128)
+                    })
+                    .sum();
+                assert!(owed <= fund.total_value as u128);
+                assert_eq!(
+                    fund.total_shares,
+                    members.iter().map(|member| member.shares).sum::<u64>()
+                );
+            }
+            assert!(paid > 0);
+        }
+    }
+}
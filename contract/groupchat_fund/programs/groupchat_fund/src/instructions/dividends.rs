@@ -0,0 +1,169 @@
+use anchor_lang::prelude::*;
+use crate::constants::{MAX_DIVIDEND_LEAVES, MIN_DIVIDEND_CLAIM_SECONDS};
+use crate::contexts::*;
+use crate::errors::ErrorCode as CustomError;
+use crate::events::{DividendClaimed, DividendDeclared, DividendSwept};
+use crate::instructions::governance::require_approval;
+use crate::state::{Dividend, DividendTerms, FundStatus, ProposalAction};
+use crate::validation::validate_amount;
+
+
+/// Moves `total_amount` out of the fund's NAV into a dividend escrow that
+/// members claim from with Merkle proofs. A proposal or a multisig approval
+/// covers the full terms, so it pins the exact root and amount being paid;
+/// the authority cannot declare one on its own.
+pub fn declare_dividend(ctx: Context<DeclareDividend>, terms: DividendTerms) -> Result<()> {
+    let fund_key = ctx.accounts.fund.key();
+    let clock = Clock::get()?;
+
+    require_approval(
+        &ctx.accounts.fund,
+        ctx.accounts.declarer.key(),
+        ctx.accounts.proposal.as_mut(),
+        ctx.accounts.multisig.as_ref().zip(ctx.accounts.multisig_transaction.as_mut()),
+        &ProposalAction::DeclareDividend(terms),
+    )?;
+
+    let fund = &mut ctx.accounts.fund;
+    fund.require_status(&[FundStatus::Active])?;
+    require!(
+        terms.total_amount > 0
+            && terms.leaf_count > 0
+            && terms.leaf_count <= MAX_DIVIDEND_LEAVES
+            && terms.snapshot_slot <= clock.slot,
+        CustomError::InvalidDividend
+    );
+    let earliest_deadline = clock.unix_timestamp
+        .checked_add(MIN_DIVIDEND_CLAIM_SECONDS)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+    require!(terms.claim_deadline >= earliest_deadline, CustomError::InvalidDividend);
+    require!(terms.total_amount <= fund.total_value, CustomError::InsufficientFunds);
+
+    let fund_balance = fund.to_account_info().lamports();
+    require!(fund_balance >= terms.total_amount, CustomError::InsufficientFunds);
+
+    **fund.to_account_info().try_borrow_mut_lamports()? -= terms.total_amount;
+    **ctx.accounts.dividend.to_account_info().try_borrow_mut_lamports()? += terms.total_amount;
+
+    fund.total_value = fund.total_value
+        .checked_sub(terms.total_amount)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+
+    let dividend = &mut ctx.accounts.dividend;
+    dividend.fund = fund_key;
+    dividend.dividend_id = fund.dividend_count;
+    dividend.terms = terms;
+    dividend.claimed_amount = 0;
+    dividend.declared_by = ctx.accounts.declarer.key();
+    dividend.claimed = vec![0; Dividend::bitmap_len(terms.leaf_count)];
+    dividend.version = Dividend::VERSION;
+    dividend.bump = ctx.bumps.dividend;
+
+    fund.dividend_count = fund.dividend_count
+        .checked_add(1)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+    fund.open_dividends = fund.open_dividends
+        .checked_add(1)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+
+    emit!(DividendDeclared {
+        fund: fund_key,
+        dividend_id: dividend.dividend_id,
+        terms,
+    });
+
+    msg!(
+        "Dividend {} declared: {} lamports over {} members",
+        dividend.dividend_id,
+        terms.total_amount,
+        terms.leaf_count
+    );
+    Ok(())
+}
+
+
+pub fn claim_dividend(
+    ctx: Context<ClaimDividend>,
+    index: u32,
+    amount: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    validate_amount(amount)?;
+
+    let dividend = &mut ctx.accounts.dividend;
+    let wallet = ctx.accounts.claimant.key();
+
+    require!(
+        Clock::get()?.unix_timestamp < dividend.terms.claim_deadline,
+        CustomError::ClaimDeadlinePassed
+    );
+    require!(index < dividend.terms.leaf_count, CustomError::InvalidMerkleProof);
+    require!(!dividend.is_claimed(index), CustomError::DividendAlreadyClaimed);
+    require!(
+        dividend.verify(&proof, Dividend::leaf(index, &wallet, amount)),
+        CustomError::InvalidMerkleProof
+    );
+
+    dividend.claimed_amount = dividend.claimed_amount
+        .checked_add(amount)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+    require!(
+        dividend.claimed_amount <= dividend.terms.total_amount,
+        CustomError::InsufficientFunds
+    );
+    dividend.set_claimed(index);
+
+    **dividend.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.claimant.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    emit!(DividendClaimed {
+        fund: dividend.fund,
+        dividend_id: dividend.dividend_id,
+        wallet,
+        index,
+        amount,
+    });
+
+    msg!("Claimed {} lamports from dividend {}", amount, dividend.dividend_id);
+    Ok(())
+}
+
+
+/// Returns unclaimed lamports to the fund's NAV once the claim deadline has
+/// passed and closes the escrow, refunding its rent to the declarer.
+pub fn sweep_dividend(ctx: Context<SweepDividend>) -> Result<()> {
+    let dividend = &ctx.accounts.dividend;
+
+    require!(
+        Clock::get()?.unix_timestamp >= dividend.terms.claim_deadline,
+        CustomError::ClaimWindowOpen
+    );
+
+    let unclaimed = dividend.terms.total_amount
+        .checked_sub(dividend.claimed_amount)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+
+    **ctx.accounts.dividend.to_account_info().try_borrow_mut_lamports()? -= unclaimed;
+    **ctx.accounts.fund.to_account_info().try_borrow_mut_lamports()? += unclaimed;
+
+    let fund = &mut ctx.accounts.fund;
+    fund.total_value = fund.total_value
+        .checked_add(unclaimed)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+    fund.open_dividends = fund.open_dividends
+        .checked_sub(1)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+
+    emit!(DividendSwept {
+        fund: fund.key(),
+        dividend_id: ctx.accounts.dividend.dividend_id,
+        amount: unclaimed,
+    });
+
+    msg!(
+        "Swept {} unclaimed lamports from dividend {}",
+        unclaimed,
+        ctx.accounts.dividend.dividend_id
+    );
+    Ok(())
+}
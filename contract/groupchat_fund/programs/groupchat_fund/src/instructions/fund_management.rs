@@ -1,57 +1,629 @@
 use anchor_lang::prelude::*;
+use crate::constants::*;
 use crate::contexts::*;
 use crate::errors::ErrorCode as CustomError;  // ✅ This imports YOUR custom ErrorCode
+use crate::events::{
+    AuthorityRenounced, AuthorityTransferCancelled, AuthorityTransferProposed, AuthorityTransferred,
+    ConfigPolicyChanged, FundConfigUpdated, FundDustSwept, FundPaused, FundResumed,
+    FundStatusChanged, GroupFundAdded, TradeLimitsUpdated, WindDownBegun,
+};
+use crate::state::{
+    ConfigPolicy, Fund, FundAction, FundConfigUpdate, FundStatus, FundraisingParams,
+    RedemptionEpoch, TradeLimits, WithdrawalGate,
+};
+use crate::instructions::allowlist::init_allowlist;
+use crate::instructions::rewards::apply_declare_rewards;
+use crate::validation::{validate_amount, validate_fee_bps, validate_fund_name};
 
 
 pub fn initialize_fund(
     ctx: Context<InitializeFund>,
-    group_id: String,
     fund_name: String,
     min_contribution: u64,
     trading_fee_bps: u16,
+    fundraising: Option<FundraisingParams>,
 ) -> Result<()> {
+    validate_fund_name(&fund_name)?;
+    validate_amount(min_contribution)?;
+    validate_fee_bps(trading_fee_bps, MAX_TRADING_FEE_BPS)?;
+    if let Some(params) = fundraising {
+        require!(
+            params.target > 0
+                && params.hard_cap >= params.target
+                && params.deadline > Clock::get()?.unix_timestamp,
+            CustomError::InvalidFundraisingParams
+        );
+    }
+
+    let group = &mut ctx.accounts.group;
     let fund = &mut ctx.accounts.fund;
+    fund.version = Fund::VERSION;
     fund.authority = ctx.accounts.authority.key();
-    fund.group_id = group_id.clone();
+    fund.trader = ctx.accounts.authority.key();
+    fund.group_id = group.group_id.clone();
     fund.fund_name = fund_name;
     fund.total_shares = 0;
     fund.total_value = 0;
     fund.min_contribution = min_contribution;
     fund.trading_fee_bps = trading_fee_bps;
-    fund.is_active = true;
+    fund.status = if fundraising.is_some() {
+        FundStatus::Fundraising
+    } else {
+        FundStatus::Active
+    };
     fund.bump = ctx.bumps.fund;
+    fund.proposal_count = 0;
+    fund.timelock_delays = [0; MAX_ACTION_KINDS];
+    fund.action_count = 0;
+    fund.pending_authority = None;
+    fund.members_only = group.default_policies.members_only;
+    fund.guardian = None;
+    fund.guardian_paused_at = 0;
+    fund.config_policy = group.default_policies.config_policy;
+    fund.redemptions = RedemptionEpoch {
+        started_at: Clock::get()?.unix_timestamp,
+        duration: DEFAULT_REDEMPTION_EPOCH_SECONDS,
+        ..RedemptionEpoch::default()
+    };
+    fund.lockup_seconds = group.default_policies.lockup_seconds;
+    fund.withdrawal_gate = WithdrawalGate {
+        window_seconds: DEFAULT_GATE_WINDOW_SECONDS,
+        ..WithdrawalGate::default()
+    };
+    fund.asset_count = 0;
+    fund.exit_fee_bps = group.default_policies.exit_fee_bps;
+    fund.member_count = 0;
+    fund.round_count = 0;
+    fund.active_round = None;
+    fund.dividend_count = 0;
+    fund.acc_reward_per_share = 0;
+    fund.reward_reserve = 0;
+    fund.status_before_pause = fund.status;
+    fund.fundraising = fundraising;
+    fund.group_hash = group.group_hash;
+    fund.fund_index = group.fund_count;
+    fund.legacy_address = false;
+    fund.trade_limits = TradeLimits::default();
+    fund.open_dividends = 0;
+
+    group.fund_count = group.fund_count
+        .checked_add(1)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+    group.funds.push(fund.key());
+
+    init_allowlist(&mut ctx.accounts.allowlist, fund.key(), ctx.bumps.allowlist);
+
+    emit!(GroupFundAdded {
+        group: group.key(),
+        fund: fund.key(),
+        fund_index: fund.fund_index,
+    });
     
-    msg!("Fund initialized for group: {}", group_id);
+    msg!("Fund {} initialized for group: {}", fund.fund_index, fund.group_id);
     msg!("Authority: {}", ctx.accounts.authority.key());
     Ok(())
 }
 
 
 pub fn close_fund(ctx: Context<CloseFund>) -> Result<()> {
-    let fund = &ctx.accounts.fund;
+    let fund = &mut ctx.accounts.fund;
 
     require!(
         ctx.accounts.authority.key() == fund.authority,
         CustomError::UnauthorizedClose
     );
 
-    require!(fund.total_value == 0, CustomError::FundNotEmpty);      // ✅ CHANGED
-    require!(fund.total_shares == 0, CustomError::SharesRemaining);  // ✅ CHANGED
+    apply_immediately(fund, &FundAction::CloseFund)
+}
+
+
+pub fn pause_fund(ctx: Context<PauseFund>) -> Result<()> {
+    apply_immediately(&mut ctx.accounts.fund, &FundAction::PauseFund)
+}
+
+
+pub fn resume_fund(ctx: Context<ResumeFund>) -> Result<()> {
+    apply_immediately(&mut ctx.accounts.fund, &FundAction::ResumeFund)
+}
+
+
+pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+    apply_immediately(
+        &mut ctx.accounts.fund,
+        &FundAction::ProposeAuthority { new_authority },
+    )
+}
+
 
-    msg!("Fund closed for group: {}", fund.group_id);
+pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let fund = &mut ctx.accounts.fund;
+    let previous_authority = fund.authority;
+    let new_authority = ctx.accounts.new_authority.key();
+
+    // A trader key that belonged to the departing authority leaves with it.
+    if fund.trader == previous_authority {
+        fund.trader = new_authority;
+    }
+    fund.authority = new_authority;
+    fund.pending_authority = None;
+
+    emit!(AuthorityTransferred {
+        fund: fund.key(),
+        previous_authority,
+        new_authority,
+    });
+
+    msg!("Authority transferred from {} to {}", previous_authority, new_authority);
     Ok(())
 }
 
 
-pub fn pause_fund(ctx: Context<PauseFund>) -> Result<()> {
-    ctx.accounts.fund.is_active = false;
-    msg!("Fund paused");
+pub fn cancel_authority_transfer(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+    apply_immediately(&mut ctx.accounts.fund, &FundAction::CancelAuthorityTransfer)
+}
+
+
+pub fn update_fund_config(ctx: Context<UpdateFundConfig>, update: FundConfigUpdate) -> Result<()> {
+    apply_immediately(&mut ctx.accounts.fund, &FundAction::UpdateConfig(update))
+}
+
+
+/// Tightens the trade limits. The authority is usually also the trader, so
+/// loosening them has to go through a vote or a timelock instead.
+pub fn set_trade_limits(
+    ctx: Context<SetTradeLimits>,
+    max_trade_bps: u16,
+    max_daily_volume: u64,
+) -> Result<()> {
+    apply_immediately(
+        &mut ctx.accounts.fund,
+        &FundAction::SetTradeLimits { max_trade_bps, max_daily_volume },
+    )
+}
+
+
+pub fn renounce_authority(ctx: Context<RenounceAuthority>) -> Result<()> {
+    apply_immediately(&mut ctx.accounts.fund, &FundAction::RenounceAuthority)
+}
+
+
+pub fn begin_wind_down(ctx: Context<BeginWindDown>) -> Result<()> {
+    apply_immediately(&mut ctx.accounts.fund, &FundAction::BeginWindDown)
+}
+
+
+/// Direct authority path; only allowed while the action has no timelock delay.
+pub(crate) fn apply_immediately(fund: &mut Account<Fund>, action: &FundAction) -> Result<()> {
+    require!(
+        fund.required_delay(action) == 0,
+        CustomError::TimelockRequired
+    );
+    apply_fund_action(fund, action, ActionOrigin::Authority)
+}
+
+
+pub fn guardian_pause(ctx: Context<GuardianPause>, reason_code: u8) -> Result<()> {
+    let fund = &mut ctx.accounts.fund;
+
+    transition_status(fund, FundStatus::Paused)?;
+    fund.guardian_paused_at = Clock::get()?.unix_timestamp;
+
+    emit!(FundPaused {
+        fund: fund.key(),
+        paused_by: ctx.accounts.guardian.key(),
+        reason_code,
+    });
+
+    msg!("Fund paused by guardian (reason {})", reason_code);
     Ok(())
 }
 
 
-pub fn resume_fund(ctx: Context<ResumeFund>) -> Result<()> {
-    ctx.accounts.fund.is_active = true;
-    msg!("Fund resumed");
+/// Where a privileged action came from. Timelocked and multisig actions
+/// carry the delay they were held for before they could run.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ActionOrigin {
+    Authority,
+    Timelock { waited: i64 },
+    Multisig { waited: i64 },
+    Governance,
+}
+
+impl ActionOrigin {
+    /// Whether the action was held for at least `MIN_POLICY_TIMELOCK_SECONDS`.
+    /// The fund's current delay says nothing about this: it may have been
+    /// raised after the action was queued.
+    fn waited_policy_delay(self) -> bool {
+        match self {
+            ActionOrigin::Timelock { waited } | ActionOrigin::Multisig { waited } => {
+                waited >= MIN_POLICY_TIMELOCK_SECONDS
+            }
+            ActionOrigin::Authority | ActionOrigin::Governance => false,
+        }
+    }
+}
+
+
+/// Applies a privileged action to the fund. `CloseFund` only validates that
+/// the fund is empty; closing the account is left to the caller's context.
+pub(crate) fn apply_fund_action(
+    fund: &mut Account<Fund>,
+    action: &FundAction,
+    origin: ActionOrigin,
+) -> Result<()> {
+    // While the fund has an authority, members may only resume it, change
+    // its configuration and pay themselves rewards; the rest needs a
+    // members-only fund.
+    if origin == ActionOrigin::Governance && !fund.members_only {
+        require!(
+            matches!(
+                action,
+                FundAction::ResumeFund
+                    | FundAction::UpdateConfig(_)
+                    | FundAction::SetConfigPolicy { .. }
+                    | FundAction::SetTradeLimits { .. }
+                    | FundAction::DeclareRewards { .. }
+            ),
+            CustomError::GovernanceActionNotAllowed
+        );
+    }
+
+    match action {
+        FundAction::PauseFund => {
+            transition_status(fund, FundStatus::Paused)?;
+            emit!(FundPaused {
+                fund: fund.key(),
+                paused_by: fund.authority,
+                reason_code: PAUSE_REASON_AUTHORITY,
+            });
+            msg!("Fund paused");
+        }
+        FundAction::ResumeFund => {
+            require!(fund.status == FundStatus::Paused, CustomError::InvalidStatusTransition);
+            // A guardian pause is lifted by governance, or by the authority
+            // once the guardian lock has run out.
+            if fund.guardian_paused_at != 0 && origin != ActionOrigin::Governance {
+                let unlocks_at = fund.guardian_paused_at
+                    .checked_add(GUARDIAN_PAUSE_LOCK_SECONDS)
+                    .ok_or(CustomError::ArithmeticOverflow)?;
+                require!(
+                    Clock::get()?.unix_timestamp >= unlocks_at,
+                    CustomError::GuardianPauseActive
+                );
+            }
+            let resume_to = fund.status_before_pause;
+            transition_status(fund, resume_to)?;
+            fund.guardian_paused_at = 0;
+            emit!(FundResumed { fund: fund.key() });
+            msg!("Fund resumed");
+        }
+        FundAction::CloseFund => {
+            // Declared rewards still belong to members who have not claimed
+            // them, so they are never swept with the account.
+            require!(fund.reward_reserve == 0, CustomError::RewardsUnclaimed);
+            // Sweeping an escrow needs the fund account, so it must outlive them.
+            require!(fund.open_dividends == 0, CustomError::DividendsUnswept);
+
+            // Once a wound-down fund has paid out every share, what is left
+            // is rounding dust and retained fees; it goes with the account.
+            if fund.status == FundStatus::WindingDown && fund.total_shares == 0 && fund.active_round.is_none() {
+                let dust = fund.total_value;
+                fund.total_value = 0;
+                emit!(FundDustSwept { fund: fund.key(), amount: dust });
+                msg!("Swept {} lamports of dust", dust);
+            }
+            require!(fund.total_value == 0, CustomError::FundNotEmpty);
+            require!(fund.total_shares == 0, CustomError::SharesRemaining);
+            transition_status(fund, FundStatus::Closed)?;
+            msg!("Fund closed for group: {}", fund.group_id);
+        }
+        FundAction::SetTimelockDelay { kind, delay } => {
+            require!(
+                (0..=MAX_TIMELOCK_DELAY_SECONDS).contains(delay),
+                CustomError::InvalidTimelockDelay
+            );
+            fund.timelock_delays[*kind as usize] = *delay;
+            msg!("Timelock delay for {:?} set to {} seconds", kind, delay);
+        }
+        FundAction::SetTrader { trader } => {
+            fund.trader = *trader;
+            msg!("Trader set to {}", trader);
+        }
+        FundAction::SetGuardian { guardian } => {
+            fund.guardian = *guardian;
+            msg!("Guardian set to {:?}", guardian);
+        }
+        FundAction::UpdateConfig(update) => {
+            check_config_policy(fund, origin)?;
+
+            let before = fund.config();
+            if let Some(min_contribution) = update.min_contribution {
+                validate_amount(min_contribution)?;
+                fund.min_contribution = min_contribution;
+            }
+            if let Some(trading_fee_bps) = update.trading_fee_bps {
+                validate_fee_bps(trading_fee_bps, MAX_TRADING_FEE_BPS)?;
+                fund.trading_fee_bps = trading_fee_bps;
+            }
+            if let Some(fund_name) = &update.fund_name {
+                validate_fund_name(fund_name)?;
+                fund.fund_name = fund_name.clone();
+            }
+            if let Some(duration) = update.redemption_epoch_seconds {
+                require!(
+                    (MIN_REDEMPTION_EPOCH_SECONDS..=MAX_REDEMPTION_EPOCH_SECONDS).contains(&duration),
+                    CustomError::InvalidConfigValue
+                );
+                fund.redemptions.duration = duration;
+            }
+            if let Some(lockup_seconds) = update.lockup_seconds {
+                require!(
+                    (0..=MAX_LOCKUP_SECONDS).contains(&lockup_seconds),
+                    CustomError::InvalidConfigValue
+                );
+                fund.lockup_seconds = lockup_seconds;
+            }
+            if let Some(gate_bps) = update.withdrawal_gate_bps {
+                require!(
+                    gate_bps as u128 <= BPS_DENOMINATOR,
+                    CustomError::InvalidConfigValue
+                );
+                fund.withdrawal_gate.gate_bps = gate_bps;
+                fund.withdrawal_gate.window_started_at = 0;
+            }
+            if let Some(window_seconds) = update.withdrawal_gate_window_seconds {
+                require!(
+                    (MIN_GATE_WINDOW_SECONDS..=MAX_GATE_WINDOW_SECONDS).contains(&window_seconds),
+                    CustomError::InvalidConfigValue
+                );
+                fund.withdrawal_gate.window_seconds = window_seconds;
+                fund.withdrawal_gate.window_started_at = 0;
+            }
+            if let Some(exit_fee_bps) = update.exit_fee_bps {
+                validate_fee_bps(exit_fee_bps, MAX_EXIT_FEE_BPS)?;
+                fund.exit_fee_bps = exit_fee_bps;
+            }
+
+            emit!(FundConfigUpdated {
+                fund: fund.key(),
+                before,
+                after: fund.config(),
+            });
+            msg!("Fund config updated");
+        }
+        FundAction::SetConfigPolicy { policy } => {
+            check_config_policy(fund, origin)?;
+
+            let previous = fund.config_policy;
+            fund.config_policy = *policy;
+            emit!(ConfigPolicyChanged {
+                fund: fund.key(),
+                previous,
+                current: *policy,
+            });
+            msg!("Config policy set to {:?}", policy);
+        }
+        FundAction::ProposeAuthority { new_authority } => {
+            fund.pending_authority = Some(*new_authority);
+            emit!(AuthorityTransferProposed {
+                fund: fund.key(),
+                current_authority: fund.authority,
+                pending_authority: *new_authority,
+            });
+            msg!("Authority transfer to {} proposed", new_authority);
+        }
+        FundAction::CancelAuthorityTransfer => {
+            let pending_authority = fund
+                .pending_authority
+                .take()
+                .ok_or(CustomError::NoPendingAuthority)?;
+            emit!(AuthorityTransferCancelled {
+                fund: fund.key(),
+                pending_authority,
+            });
+            msg!("Authority transfer to {} cancelled", pending_authority);
+        }
+        FundAction::RenounceAuthority => {
+            // From here on privileged actions only run through member proposals.
+            emit!(AuthorityRenounced {
+                fund: fund.key(),
+                previous_authority: fund.authority,
+            });
+            fund.authority = Pubkey::default();
+            fund.pending_authority = None;
+            fund.members_only = true;
+            msg!("Authority renounced, fund is now members-only");
+        }
+        FundAction::BeginWindDown => {
+            fund.require_status(&[FundStatus::Fundraising, FundStatus::Active])?;
+            transition_status(fund, FundStatus::WindingDown)?;
+            emit!(WindDownBegun {
+                fund: fund.key(),
+                total_shares: fund.total_shares,
+                total_value: fund.total_value,
+            });
+            msg!("Fund is winding down: contributions frozen, liquidation trades only");
+        }
+        FundAction::DeclareRewards { amount } => {
+            apply_declare_rewards(fund, *amount)?;
+        }
+        FundAction::SetTradeLimits { max_trade_bps, max_daily_volume } => {
+            check_trade_limits_change(fund, action, origin)?;
+            require!(
+                *max_trade_bps as u128 <= BPS_DENOMINATOR,
+                CustomError::InvalidConfigValue
+            );
+
+            fund.trade_limits.max_trade_bps = *max_trade_bps;
+            fund.trade_limits.max_daily_volume = *max_daily_volume;
+
+            emit!(TradeLimitsUpdated {
+                fund: fund.key(),
+                max_trade_bps: *max_trade_bps,
+                max_daily_volume: *max_daily_volume,
+            });
+            msg!("Trade limits: {} bps per trade, {} lamports per day", max_trade_bps, max_daily_volume);
+        }
+    }
+    Ok(())
+}
+
+
+/// The only place a fund's status is written. Moves outside the transition
+/// table in `FundStatus::can_transition_to` are rejected.
+pub(crate) fn transition_status(fund: &mut Account<Fund>, to: FundStatus) -> Result<()> {
+    let from = fund.status;
+    require!(from.can_transition_to(to), CustomError::InvalidStatusTransition);
+
+    if to == FundStatus::Paused {
+        fund.status_before_pause = from;
+    }
+    fund.status = to;
+
+    emit!(FundStatusChanged {
+        fund: fund.key(),
+        from,
+        to,
+    });
+    msg!("Fund status: {:?} -> {:?}", from, to);
     Ok(())
-}
\ No newline at end of file
+}
+
+
+/// Trade limits follow the config policy, and loosening them also needs a
+/// vote or an action held for `MIN_POLICY_TIMELOCK_SECONDS`, so a trader who
+/// holds the authority cannot lift their own limits on the spot.
+fn check_trade_limits_change(fund: &Fund, action: &FundAction, origin: ActionOrigin) -> Result<()> {
+    check_config_policy(fund, origin)?;
+
+    if let FundAction::SetTradeLimits { max_trade_bps, max_daily_volume } = action {
+        if fund.trade_limits.is_loosened_by(*max_trade_bps, *max_daily_volume) {
+            require!(
+                origin == ActionOrigin::Governance || origin.waited_policy_delay(),
+                CustomError::TradeLimitLooseningNotAllowed
+            );
+        }
+    }
+    Ok(())
+}
+
+
+/// Config changes follow the fund's `ConfigPolicy`. Governance can always act
+/// once the authority has been renounced.
+fn check_config_policy(fund: &Fund, origin: ActionOrigin) -> Result<()> {
+    let allowed = match fund.config_policy {
+        ConfigPolicy::AuthorityOnly => {
+            origin != ActionOrigin::Governance || fund.members_only
+        }
+        ConfigPolicy::AuthorityWithTimelock => match origin {
+            ActionOrigin::Governance => fund.members_only,
+            _ => origin.waited_policy_delay(),
+        },
+        ConfigPolicy::Governance => origin == ActionOrigin::Governance,
+    };
+    require!(allowed, CustomError::ConfigUpdateNotAllowed);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{ActionKind, QueuedAction, TradeLimits};
+    use crate::test_utils::fund;
+
+    fn limited_fund(config_policy: ConfigPolicy) -> Fund {
+        let mut fund = fund();
+        fund.config_policy = config_policy;
+        fund.trade_limits = TradeLimits {
+            max_trade_bps: 500,
+            max_daily_volume: 1_000,
+            ..TradeLimits::default()
+        };
+        fund
+    }
+
+    fn set_limits(max_trade_bps: u16, max_daily_volume: u64) -> FundAction {
+        FundAction::SetTradeLimits { max_trade_bps, max_daily_volume }
+    }
+
+    #[test]
+    fn authority_can_tighten_but_not_loosen_limits() {
+        let fund = limited_fund(ConfigPolicy::AuthorityOnly);
+
+        assert!(check_trade_limits_change(&fund, &set_limits(100, 500), ActionOrigin::Authority).is_ok());
+        assert_eq!(
+            check_trade_limits_change(&fund, &set_limits(1_000, 1_000), ActionOrigin::Authority).err(),
+            Some(CustomError::TradeLimitLooseningNotAllowed.into())
+        );
+        assert_eq!(
+            check_trade_limits_change(&fund, &set_limits(500, 0), ActionOrigin::Authority).err(),
+            Some(CustomError::TradeLimitLooseningNotAllowed.into())
+        );
+    }
+
+    #[test]
+    fn loosening_through_a_timelock_needs_the_minimum_wait() {
+        let mut fund = limited_fund(ConfigPolicy::AuthorityOnly);
+        let loosen = set_limits(0, 0);
+        let short = MIN_POLICY_TIMELOCK_SECONDS - 1;
+
+        assert_eq!(
+            check_trade_limits_change(&fund, &loosen, ActionOrigin::Timelock { waited: short }).err(),
+            Some(CustomError::TradeLimitLooseningNotAllowed.into())
+        );
+
+        // A delay raised after queueing does not count; only the wait does.
+        fund.timelock_delays[ActionKind::SetTradeLimits as usize] = MIN_POLICY_TIMELOCK_SECONDS;
+        assert_eq!(
+            check_trade_limits_change(&fund, &loosen, ActionOrigin::Multisig { waited: short }).err(),
+            Some(CustomError::TradeLimitLooseningNotAllowed.into())
+        );
+
+        let waited = MIN_POLICY_TIMELOCK_SECONDS;
+        assert!(check_trade_limits_change(&fund, &loosen, ActionOrigin::Timelock { waited }).is_ok());
+        assert!(check_trade_limits_change(&fund, &loosen, ActionOrigin::Multisig { waited }).is_ok());
+    }
+
+    #[test]
+    fn governance_can_loosen_limits_when_the_policy_lets_it() {
+        let loosen = set_limits(2_000, 5_000);
+
+        let fund = limited_fund(ConfigPolicy::Governance);
+        assert!(check_trade_limits_change(&fund, &loosen, ActionOrigin::Governance).is_ok());
+
+        let fund = limited_fund(ConfigPolicy::AuthorityOnly);
+        assert_eq!(
+            check_trade_limits_change(&fund, &loosen, ActionOrigin::Governance).err(),
+            Some(CustomError::ConfigUpdateNotAllowed.into())
+        );
+    }
+
+    #[test]
+    fn raising_the_delay_after_queueing_does_not_satisfy_the_policy() {
+        let mut fund = fund();
+        fund.config_policy = ConfigPolicy::AuthorityWithTimelock;
+        let action = set_limits(100, 500);
+        let queue = |fund: &Fund, now: i64| QueuedAction {
+            version: QueuedAction::VERSION,
+            fund: Pubkey::default(),
+            action_id: 0,
+            action: action.clone(),
+            queued_at: now,
+            eta: now + fund.required_delay(&action),
+            bump: 0,
+        };
+
+        let early = queue(&fund, 1_000);
+        fund.timelock_delays[ActionKind::SetTradeLimits as usize] = MIN_POLICY_TIMELOCK_SECONDS;
+
+        let origin = ActionOrigin::Timelock { waited: early.delay() };
+        assert_eq!(
+            check_config_policy(&fund, origin).err(),
+            Some(CustomError::ConfigUpdateNotAllowed.into())
+        );
+
+        let late = queue(&fund, 2_000);
+        let origin = ActionOrigin::Timelock { waited: late.delay() };
+        assert!(check_config_policy(&fund, origin).is_ok());
+    }
+}
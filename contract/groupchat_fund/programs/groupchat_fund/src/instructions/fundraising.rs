@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::contexts::*;
+use crate::errors::ErrorCode as CustomError;
+use crate::events::RefundClaimed;
+use crate::instructions::fund_management::transition_status;
+use crate::state::FundStatus;
+
+
+/// Ends a raise that met its target and opens the fund for trading. The
+/// authority may close the raise early; anyone may once the deadline passes.
+pub fn finalize_fundraising(ctx: Context<FinalizeFundraising>) -> Result<()> {
+    let fund = &mut ctx.accounts.fund;
+
+    fund.require_status(&[FundStatus::Fundraising])?;
+    let params = fund.fundraising.ok_or(CustomError::InvalidFundStatus)?;
+
+    require!(fund.total_value >= params.target, CustomError::FundraisingTargetNotMet);
+    require!(
+        Clock::get()?.unix_timestamp >= params.deadline
+            || ctx.accounts.caller.key() == fund.authority,
+        CustomError::FundraisingStillOpen
+    );
+
+    transition_status(fund, FundStatus::Active)?;
+
+    msg!("Fundraising closed with {} lamports raised", fund.total_value);
+    Ok(())
+}
+
+
+/// Returns a member's full contribution, without fees, once a raise has
+/// missed its deadline without reaching the target.
+pub fn claim_refund(ctx: Context<ClaimRefund>) -> Result<()> {
+    let fund = &ctx.accounts.fund;
+
+    fund.require_status(&[FundStatus::Fundraising])?;
+    let params = fund.fundraising.ok_or(CustomError::InvalidFundStatus)?;
+    require!(
+        Clock::get()?.unix_timestamp >= params.deadline && fund.total_value < params.target,
+        CustomError::RefundsNotAvailable
+    );
+
+    let amount = ctx.accounts.member.total_contributed;
+    require!(amount > 0, CustomError::InsufficientFunds);
+
+    let fund_balance = fund.to_account_info().lamports();
+    require!(fund_balance >= amount, CustomError::InsufficientFunds);
+
+    **ctx.accounts.fund.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.member_wallet.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    let fund = &mut ctx.accounts.fund;
+    let member = &mut ctx.accounts.member;
+
+    member.settle_rewards(fund.acc_reward_per_share);
+    fund.total_value = fund.total_value
+        .checked_sub(amount)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+    fund.total_shares = fund.total_shares
+        .checked_sub(member.shares)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+
+    member.shares = 0;
+    member.total_contributed = 0;
+    member.lots.clear();
+    member.record_checkpoint(Clock::get()?.slot);
+    member.sync_reward_debt(fund.acc_reward_per_share);
+
+    emit!(RefundClaimed {
+        fund: fund.key(),
+        wallet: member.wallet,
+        amount,
+    });
+
+    msg!("Refunded {} lamports", amount);
+    Ok(())
+}
@@ -0,0 +1,258 @@
+use anchor_lang::prelude::*;
+use crate::constants::{MIN_POLICY_TIMELOCK_SECONDS, VOTING_PERIOD_SECONDS};
+use crate::contexts::*;
+use crate::errors::ErrorCode as CustomError;
+use crate::events::ProposalExecuted;
+use crate::instructions::fund_management::{apply_fund_action, ActionOrigin};
+use crate::instructions::multisig::consume_multisig_approval;
+use crate::state::{
+    Fund, FundAction, Member, Multisig, MultisigTransaction, Proposal, ProposalAction, VoteRecord,
+};
+use crate::validation::validate_description;
+
+
+pub fn create_proposal(
+    ctx: Context<CreateProposal>,
+    description: String,
+    action: Option<ProposalAction>,
+) -> Result<()> {
+    validate_description(&description)?;
+
+    let member = &ctx.accounts.member;
+
+    require!(member.is_active, CustomError::MemberNotActive);
+    require!(member.shares > 0, CustomError::InsufficientShares);
+
+    let clock = Clock::get()?;
+    let fund = &mut ctx.accounts.fund;
+    let proposal = &mut ctx.accounts.proposal;
+
+    proposal.fund = fund.key();
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.proposal_id = fund.proposal_count;
+    proposal.description = description;
+    proposal.created_slot = clock.slot;
+    proposal.voting_ends_at = clock
+        .unix_timestamp
+        .checked_add(VOTING_PERIOD_SECONDS)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+    proposal.total_shares = fund.total_shares;
+    proposal.yes_shares = 0;
+    proposal.no_shares = 0;
+    proposal.action = action;
+    proposal.executed = false;
+    proposal.version = Proposal::VERSION;
+    proposal.bump = ctx.bumps.proposal;
+
+    fund.proposal_count = fund.proposal_count
+        .checked_add(1)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+
+    msg!("Proposal {} created at slot {}", proposal.proposal_id, proposal.created_slot);
+    msg!("Voting ends at: {}", proposal.voting_ends_at);
+    Ok(())
+}
+
+
+/// Delegators voted for by the caller are passed as `remaining_accounts`.
+pub fn cast_vote<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CastVote<'info>>,
+    approve: bool,
+) -> Result<()> {
+    let member = &ctx.accounts.member;
+    let proposal = &mut ctx.accounts.proposal;
+    let snapshot_slot = proposal.snapshot_slot();
+
+    require!(member.is_active, CustomError::MemberNotActive);
+    require!(
+        Clock::get()?.unix_timestamp < proposal.voting_ends_at,
+        CustomError::VotingClosed
+    );
+
+    // Weight comes from the checkpointed balance just before the proposal
+    // was created, so shares bought in or after that slot carry no vote.
+    let own_shares = if member.delegate.is_none() && member.delegation_settled_at(snapshot_slot) {
+        member.shares_at(snapshot_slot)
+    } else {
+        0
+    };
+
+    let fund_key = ctx.accounts.fund.key();
+    let voter_key = ctx.accounts.voter.key();
+    let mut counted: Vec<Pubkey> = Vec::with_capacity(ctx.remaining_accounts.len());
+    let mut delegated_shares: u64 = 0;
+
+    for account_info in ctx.remaining_accounts.iter() {
+        let delegator: Account<'info, Member> = Account::try_from(account_info)?;
+        let (expected, _) = Pubkey::find_program_address(
+            &[b"member", fund_key.as_ref(), delegator.wallet.as_ref()],
+            ctx.program_id,
+        );
+        require_keys_eq!(account_info.key(), expected, CustomError::InvalidDelegation);
+        require!(
+            delegator.delegate == Some(voter_key)
+                && delegator.delegation_settled_at(snapshot_slot),
+            CustomError::InvalidDelegation
+        );
+        require!(
+            !counted.contains(&account_info.key()),
+            CustomError::InvalidDelegation
+        );
+        counted.push(account_info.key());
+
+        delegated_shares = delegated_shares
+            .checked_add(delegator.shares_at(snapshot_slot))
+            .ok_or(CustomError::ArithmeticOverflow)?;
+    }
+
+    let weight = own_shares
+        .checked_add(delegated_shares)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+    require!(weight > 0, CustomError::NoVotingPower);
+
+    if approve {
+        proposal.yes_shares = proposal.yes_shares
+            .checked_add(weight)
+            .ok_or(CustomError::ArithmeticOverflow)?;
+    } else {
+        proposal.no_shares = proposal.no_shares
+            .checked_add(weight)
+            .ok_or(CustomError::ArithmeticOverflow)?;
+    }
+
+    let vote_record = &mut ctx.accounts.vote_record;
+    vote_record.proposal = proposal.key();
+    vote_record.voter = ctx.accounts.voter.key();
+    vote_record.approve = approve;
+    vote_record.shares = weight;
+    vote_record.delegated_shares = delegated_shares;
+    vote_record.version = VoteRecord::VERSION;
+    vote_record.bump = ctx.bumps.vote_record;
+
+    msg!(
+        "Vote cast on proposal {}: {} with {} shares ({} delegated)",
+        proposal.proposal_id,
+        if approve { "YES" } else { "NO" },
+        weight,
+        delegated_shares
+    );
+    Ok(())
+}
+
+
+pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+
+    require!(!proposal.executed, CustomError::ProposalAlreadyExecuted);
+    let action = match proposal.action.clone().ok_or(CustomError::ProposalHasNoAction)? {
+        ProposalAction::Fund(action) => action,
+        _ => return err!(CustomError::WrongProposalAction),
+    };
+
+    // The action's timelock starts once voting has closed.
+    let eta = proposal.voting_ends_at
+        .checked_add(ctx.accounts.fund.required_delay(&action))
+        .ok_or(CustomError::ArithmeticOverflow)?;
+    require!(
+        Clock::get()?.unix_timestamp >= eta,
+        CustomError::TimelockNotElapsed
+    );
+    require!(proposal.has_passed(), CustomError::ProposalNotPassed);
+
+    apply_fund_action(&mut ctx.accounts.fund, &action, ActionOrigin::Governance)?;
+    ctx.accounts.proposal.executed = true;
+
+    emit!(ProposalExecuted {
+        fund: ctx.accounts.fund.key(),
+        proposal_id: ctx.accounts.proposal.proposal_id,
+        action: ProposalAction::Fund(action.clone()),
+    });
+
+    if action == FundAction::CloseFund {
+        ctx.accounts.fund.close(ctx.accounts.proposer.to_account_info())?;
+    }
+
+    Ok(())
+}
+
+
+/// Gate for instructions the fund authority may run directly and members
+/// may approve by vote: with a proposal, it must approve `expected`; with a
+/// multisig transaction, the multisig holding the authority must have
+/// approved `expected`; with neither, `signer` must be the authority.
+pub(crate) fn require_authority_or_approval(
+    fund: &Account<Fund>,
+    signer: Pubkey,
+    proposal: Option<&mut Account<Proposal>>,
+    multisig: Option<(&Account<Multisig>, &mut Account<MultisigTransaction>)>,
+    expected: &ProposalAction,
+) -> Result<()> {
+    match (proposal, multisig) {
+        (Some(proposal), None) => consume_approval(fund.key(), proposal, expected),
+        (None, Some((multisig, transaction))) => {
+            consume_multisig_approval(fund, multisig, transaction, signer, expected)
+        }
+        (None, None) => {
+            require_keys_eq!(signer, fund.authority, CustomError::NotAuthorityOrApproved);
+            Ok(())
+        }
+        (Some(_), Some(_)) => err!(CustomError::NotAuthorityOrApproved),
+    }
+}
+
+
+/// Gate for instructions that move fund capital out of the NAV, which the
+/// authority may not run on its own: a passed proposal must approve
+/// `expected`, or the fund's multisig must have approved it at least
+/// `MIN_POLICY_TIMELOCK_SECONDS` ago, the wait a timelocked policy change
+/// needs.
+pub(crate) fn require_approval(
+    fund: &Account<Fund>,
+    signer: Pubkey,
+    proposal: Option<&mut Account<Proposal>>,
+    multisig: Option<(&Account<Multisig>, &mut Account<MultisigTransaction>)>,
+    expected: &ProposalAction,
+) -> Result<()> {
+    match (proposal, multisig) {
+        (Some(proposal), None) => consume_approval(fund.key(), proposal, expected),
+        (None, Some((multisig, transaction))) => {
+            consume_multisig_approval(fund, multisig, transaction, signer, expected)?;
+            let waited = Clock::get()?.unix_timestamp.saturating_sub(transaction.approved_at);
+            require!(waited >= MIN_POLICY_TIMELOCK_SECONDS, CustomError::TimelockNotElapsed);
+            Ok(())
+        }
+        _ => err!(CustomError::ApprovalRequired),
+    }
+}
+
+
+/// Marks a passed proposal as executed on behalf of an instruction that
+/// carries out `expected` itself. Approvals take effect as soon as voting
+/// has closed.
+pub(crate) fn consume_approval(
+    fund: Pubkey,
+    proposal: &mut Account<Proposal>,
+    expected: &ProposalAction,
+) -> Result<()> {
+    require_keys_eq!(proposal.fund, fund, CustomError::WrongProposalAction);
+    require!(!proposal.executed, CustomError::ProposalAlreadyExecuted);
+    require!(
+        proposal.action.as_ref() == Some(expected),
+        CustomError::WrongProposalAction
+    );
+    require!(
+        Clock::get()?.unix_timestamp >= proposal.voting_ends_at,
+        CustomError::TimelockNotElapsed
+    );
+    require!(proposal.has_passed(), CustomError::ProposalNotPassed);
+
+    proposal.executed = true;
+
+    emit!(ProposalExecuted {
+        fund,
+        proposal_id: proposal.proposal_id,
+        action: expected.clone(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::contexts::*;
+use crate::errors::ErrorCode as CustomError;
+use crate::events::{GroupAdminsUpdated, GroupPoliciesUpdated, GroupRegistered, RegistrarSet};
+use crate::state::{Group, GroupPolicies, ProgramConfig};
+use crate::validation::{validate_fee_bps, validate_group_admins, validate_group_id};
+
+
+/// Creates the program config. Only the upgrade authority can, so the
+/// first caller after deployment cannot pick the registrar.
+pub fn initialize_program_config(
+    ctx: Context<InitializeProgramConfig>,
+    registrar: Pubkey,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.version = ProgramConfig::VERSION;
+    config.registrar = registrar;
+    config.bump = ctx.bumps.config;
+
+    emit!(RegistrarSet { registrar });
+
+    msg!("Program config created, registrar {}", registrar);
+    Ok(())
+}
+
+
+pub fn set_registrar(ctx: Context<SetRegistrar>, registrar: Pubkey) -> Result<()> {
+    ctx.accounts.config.registrar = registrar;
+
+    emit!(RegistrarSet { registrar });
+
+    msg!("Registrar set to {}", registrar);
+    Ok(())
+}
+
+
+/// Creates the registry for a chat. Group ids are public, so only the
+/// registrar may register one, naming the chat admins it has verified;
+/// otherwise anyone could claim a group before its own admins do. The
+/// caller passes the id's hash as well, since it seeds the address; it must
+/// match the id.
+pub fn register_group(
+    ctx: Context<RegisterGroup>,
+    group_id: String,
+    group_hash: [u8; 32],
+    admins: Vec<Pubkey>,
+) -> Result<()> {
+    validate_group_id(&group_id)?;
+    require!(group_hash == Group::hash_id(&group_id), CustomError::InvalidGroupId);
+    validate_group_admins(&admins)?;
+
+    let group = &mut ctx.accounts.group;
+    group.version = Group::VERSION;
+    group.group_hash = group_hash;
+    group.group_id = group_id;
+    group.fund_count = 0;
+    group.bump = ctx.bumps.group;
+    group.admins = admins;
+    group.default_policies = GroupPolicies::default();
+    group.funds = Vec::new();
+
+    emit!(GroupRegistered {
+        group: group.key(),
+        group_id: group.group_id.clone(),
+    });
+
+    msg!("Group registered: {}", group.group_id);
+    Ok(())
+}
+
+
+/// Replaces the admin list. Admins may remove themselves as long as
+/// someone is left.
+pub fn set_group_admins(ctx: Context<UpdateGroup>, admins: Vec<Pubkey>) -> Result<()> {
+    validate_group_admins(&admins)?;
+
+    let group = &mut ctx.accounts.group;
+    group.admins = admins;
+
+    emit!(GroupAdminsUpdated {
+        group: group.key(),
+        admins: group.admins.clone(),
+    });
+
+    msg!("Group {} now has {} admins", group.group_id, group.admins.len());
+    Ok(())
+}
+
+
+/// Sets the policies new funds in the group start with. Existing funds
+/// keep their own configuration.
+pub fn set_group_policies(ctx: Context<UpdateGroup>, policies: GroupPolicies) -> Result<()> {
+    require!(
+        (0..=MAX_LOCKUP_SECONDS).contains(&policies.lockup_seconds),
+        CustomError::InvalidConfigValue
+    );
+    validate_fee_bps(policies.exit_fee_bps, MAX_EXIT_FEE_BPS)?;
+
+    let group = &mut ctx.accounts.group;
+    group.default_policies = policies;
+
+    emit!(GroupPoliciesUpdated {
+        group: group.key(),
+        policies,
+    });
+
+    msg!("Group {} default policies updated", group.group_id);
+    Ok(())
+}
@@ -1,14 +1,100 @@
 use anchor_lang::prelude::*;
 use crate::contexts::*;
+use crate::errors::ErrorCode as CustomError;
+use crate::state::{FundStatus, LockupPreview, Member};
+use crate::validation::validate_telegram_id;
 
 pub fn add_member(ctx: Context<AddMember>, telegram_id: String) -> Result<()> {
+    validate_telegram_id(&telegram_id)?;
+
+    let fund = &mut ctx.accounts.fund;
+    fund.require_status(&[FundStatus::Fundraising, FundStatus::Active])?;
+
     let member = &mut ctx.accounts.member;
+    member.version = Member::VERSION;
     member.wallet = ctx.accounts.member_wallet.key();
     member.telegram_id = telegram_id;
     member.shares = 0;
     member.total_contributed = 0;
     member.is_active = true;
+    member.checkpoints = Vec::new();
+    member.delegate = None;
+    member.delegation_slot = 0;
+    member.delegator_count = 0;
+    member.queued_shares = 0;
+    member.lots = Vec::new();
+    member.joined_round = fund.round_count;
+    member.last_paid_round = None;
+    member.reward_debt = 0;
+    member.pending_rewards = 0;
+
+    fund.member_count = fund.member_count
+        .checked_add(1)
+        .ok_or(CustomError::ArithmeticOverflow)?;
     
     msg!("Member registered");
     Ok(())
 }
+
+
+pub fn delegate_vote(ctx: Context<DelegateVote>) -> Result<()> {
+    let member = &ctx.accounts.member;
+    let delegate_member = &ctx.accounts.delegate_member;
+
+    require!(member.is_active, CustomError::MemberNotActive);
+    require!(delegate_member.is_active, CustomError::MemberNotActive);
+    require!(
+        member.wallet != delegate_member.wallet,
+        CustomError::InvalidDelegation
+    );
+    require!(member.delegate.is_none(), CustomError::AlreadyDelegated);
+    // One level deep: members holding delegations cannot pass them on, and
+    // members who delegated cannot receive delegations.
+    require!(member.delegator_count == 0, CustomError::InvalidDelegation);
+    require!(delegate_member.delegate.is_none(), CustomError::InvalidDelegation);
+
+    let slot = Clock::get()?.slot;
+    let delegate_wallet = delegate_member.wallet;
+
+    let member = &mut ctx.accounts.member;
+    member.delegate = Some(delegate_wallet);
+    member.delegation_slot = slot;
+
+    let delegate_member = &mut ctx.accounts.delegate_member;
+    delegate_member.delegator_count = delegate_member.delegator_count
+        .checked_add(1)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+
+    msg!("Voting power delegated to {}", delegate_wallet);
+    Ok(())
+}
+
+
+pub fn revoke_delegation(ctx: Context<RevokeDelegation>) -> Result<()> {
+    let slot = Clock::get()?.slot;
+
+    let member = &mut ctx.accounts.member;
+    member.delegate = None;
+    member.delegation_slot = slot;
+
+    let delegate_member = &mut ctx.accounts.delegate_member;
+    delegate_member.delegator_count = delegate_member.delegator_count
+        .checked_sub(1)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+
+    msg!("Delegation to {} revoked", delegate_member.wallet);
+    Ok(())
+}
+
+
+pub fn preview_lockup(ctx: Context<PreviewLockup>) -> Result<LockupPreview> {
+    let preview = ctx.accounts.member.lockup_preview(Clock::get()?.unix_timestamp);
+
+    msg!(
+        "Shares: {} locked, {} unlocked, {} queued",
+        preview.locked_shares,
+        preview.unlocked_shares,
+        preview.queued_shares
+    );
+    Ok(preview)
+}
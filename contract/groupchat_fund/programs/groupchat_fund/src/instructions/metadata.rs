@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::contexts::*;
+use crate::errors::ErrorCode as CustomError;
+use crate::events::FundMetadataUpdated;
+use crate::instructions::governance::require_authority_or_approval;
+use crate::state::{FundMetadata, FundMetadataContent, FundStatus, ProposalAction};
+
+
+/// Creates an empty metadata account for a fund. Permissionless, since an
+/// empty account changes nothing; writing content is what is gated.
+pub fn create_fund_metadata(ctx: Context<CreateFundMetadata>) -> Result<()> {
+    let metadata = &mut ctx.accounts.metadata;
+    metadata.version = FundMetadata::VERSION;
+    metadata.fund = ctx.accounts.fund.key();
+    metadata.revision = 0;
+    metadata.updated_at = Clock::get()?.unix_timestamp;
+    metadata.bump = ctx.bumps.metadata;
+    metadata.content = FundMetadataContent::default();
+
+    msg!("Metadata account created for fund {}", metadata.fund);
+    Ok(())
+}
+
+
+/// Replaces the fund's metadata, resizing the account to fit. A proposal
+/// approves the content's hash, as the content itself may not fit in one.
+pub fn update_fund_metadata(
+    ctx: Context<UpdateFundMetadata>,
+    content: FundMetadataContent,
+) -> Result<()> {
+    let fund_key = ctx.accounts.fund.key();
+    require!(content.is_within_limits(), CustomError::MetadataTooLong);
+    ctx.accounts.fund.require_status(&[
+        FundStatus::Fundraising,
+        FundStatus::Active,
+        FundStatus::Paused,
+        FundStatus::WindingDown,
+    ])?;
+
+    let content_hash = content.content_hash()?;
+    require_authority_or_approval(
+        &ctx.accounts.fund,
+        ctx.accounts.updater.key(),
+        ctx.accounts.proposal.as_mut(),
+        ctx.accounts.multisig.as_ref().zip(ctx.accounts.multisig_transaction.as_mut()),
+        &ProposalAction::UpdateMetadata { content_hash },
+    )?;
+
+    let metadata = &mut ctx.accounts.metadata;
+    metadata.content = content;
+    metadata.updated_at = Clock::get()?.unix_timestamp;
+    metadata.revision = metadata.revision
+        .checked_add(1)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+
+    emit!(FundMetadataUpdated {
+        fund: fund_key,
+        revision: metadata.revision,
+        content_hash,
+        updated_by: ctx.accounts.updater.key(),
+    });
+
+    msg!("Fund {} metadata updated to revision {}", fund_key, metadata.revision);
+    Ok(())
+}
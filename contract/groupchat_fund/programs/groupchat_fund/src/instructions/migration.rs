@@ -0,0 +1,234 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer as SystemTransfer};
+use crate::contexts::*;
+use crate::errors::ErrorCode as CustomError;
+use crate::events::AccountMigrated;
+use crate::instructions::allowlist::init_allowlist;
+use crate::state::{Fund, Group, Member};
+use crate::validation::validate_group_admins;
+
+
+/// Converts a group registry from an older layout in place. Groups from
+/// before registration was gated may have been claimed by anyone, so the
+/// registrar supplies the admins it has verified for the chat.
+pub fn migrate_group(ctx: Context<MigrateGroup>, admins: Vec<Pubkey>) -> Result<()> {
+    validate_group_admins(&admins)?;
+    let info = ctx.accounts.group.to_account_info();
+
+    let (from_version, group) = Group::migrate_from(&info.try_borrow_data()?, admins)?;
+
+    require!(group.has_address(&info.key()), CustomError::UnknownAccountLayout);
+
+    let new_len = Group::space(group.funds.len());
+    grow_account(&info, new_len, &ctx.accounts.payer, &ctx.accounts.system_program)?;
+    group.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    emit!(AccountMigrated {
+        account: info.key(),
+        from_version,
+        to_version: Group::VERSION,
+    });
+
+    msg!("Group {} migrated to version {}", group.group_id, Group::VERSION);
+    Ok(())
+}
+
+
+/// Converts a fund account from an older layout in place. Anyone may call
+/// it; the payer covers the extra rent. Every dividend escrow address the
+/// fund has derived is passed as `remaining_accounts`, in order, so the
+/// ones not yet swept can be counted.
+pub fn migrate_fund(ctx: Context<MigrateFund>) -> Result<()> {
+    let info = ctx.accounts.fund.to_account_info();
+
+    let (from_version, mut fund) =
+        Fund::migrate_from(&info.try_borrow_data()?, Clock::get()?.unix_timestamp)?;
+
+    require!(fund.has_address(&info.key()), CustomError::UnknownAccountLayout);
+    fund.open_dividends = count_open_dividends(
+        &info.key(),
+        fund.dividend_count,
+        ctx.remaining_accounts,
+        ctx.program_id,
+    )?;
+
+    grow_account(&info, Fund::SPACE, &ctx.accounts.payer, &ctx.accounts.system_program)?;
+    fund.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    if ctx.accounts.allowlist.version == 0 {
+        init_allowlist(&mut ctx.accounts.allowlist, info.key(), ctx.bumps.allowlist);
+    }
+
+    emit!(AccountMigrated {
+        account: info.key(),
+        from_version,
+        to_version: Fund::VERSION,
+    });
+
+    msg!("Fund {} migrated to version {}", fund.group_id, Fund::VERSION);
+    Ok(())
+}
+
+
+/// Counts the fund's dividend escrows that still hold an account. `escrows`
+/// must list the escrow address of every dividend id, so none can be left
+/// out of the count.
+fn count_open_dividends(
+    fund: &Pubkey,
+    dividend_count: u64,
+    escrows: &[AccountInfo],
+    program_id: &Pubkey,
+) -> Result<u32> {
+    require!(
+        escrows.len() as u64 == dividend_count,
+        CustomError::DividendEscrowMismatch
+    );
+
+    let mut open: u32 = 0;
+    for (dividend_id, escrow) in (0..dividend_count).zip(escrows) {
+        let (expected, _) = Pubkey::find_program_address(
+            &[b"dividend", fund.as_ref(), &dividend_id.to_le_bytes()],
+            program_id,
+        );
+        require_keys_eq!(escrow.key(), expected, CustomError::DividendEscrowMismatch);
+
+        if escrow.owner == program_id && !escrow.data_is_empty() {
+            open = open.checked_add(1).ok_or(CustomError::ArithmeticOverflow)?;
+        }
+    }
+    Ok(open)
+}
+
+
+/// Converts a member account from an older layout in place and counts it
+/// towards the fund's membership. The fund must be migrated first.
+pub fn migrate_member(ctx: Context<MigrateMember>) -> Result<()> {
+    let info = ctx.accounts.member.to_account_info();
+    let fund_key = ctx.accounts.fund.key();
+
+    let (from_version, member) =
+        Member::migrate_from(&info.try_borrow_data()?, &ctx.accounts.fund, Clock::get()?.slot)?;
+
+    let (expected, _) = Pubkey::find_program_address(
+        &[b"member", fund_key.as_ref(), member.wallet.as_ref()],
+        ctx.program_id,
+    );
+    require_keys_eq!(info.key(), expected, CustomError::UnknownAccountLayout);
+
+    grow_account(&info, Member::SPACE, &ctx.accounts.payer, &ctx.accounts.system_program)?;
+    member.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    let fund = &mut ctx.accounts.fund;
+    fund.member_count = fund.member_count
+        .checked_add(1)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+
+    emit!(AccountMigrated {
+        account: info.key(),
+        from_version,
+        to_version: Member::VERSION,
+    });
+
+    msg!("Member {} migrated to version {}", member.wallet, Member::VERSION);
+    Ok(())
+}
+
+
+/// Resizes `account` to `new_len`, with the payer covering the extra rent so
+/// none of it comes out of fund capital.
+fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    new_len: usize,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let rent = Rent::get()?;
+    let extra_rent = rent
+        .minimum_balance(new_len)
+        .saturating_sub(rent.minimum_balance(account.data_len()));
+
+    if extra_rent > 0 {
+        let cpi_context = CpiContext::new(
+            system_program.to_account_info(),
+            SystemTransfer {
+                from: payer.to_account_info(),
+                to: account.clone(),
+            },
+        );
+        transfer(cpi_context, extra_rent)?;
+    }
+
+    account.resize(new_len)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Escrow {
+        key: Pubkey,
+        owner: Pubkey,
+        lamports: u64,
+        data: Vec<u8>,
+    }
+
+    /// Escrows for dividend ids `0..count`; the ones in `swept` were closed.
+    fn escrows(fund: &Pubkey, count: u64, swept: &[u64]) -> Vec<Escrow> {
+        (0..count)
+            .map(|dividend_id| {
+                let (key, _) = Pubkey::find_program_address(
+                    &[b"dividend", fund.as_ref(), &dividend_id.to_le_bytes()],
+                    &crate::ID,
+                );
+                if swept.contains(&dividend_id) {
+                    Escrow { key, owner: Pubkey::default(), lamports: 0, data: Vec::new() }
+                } else {
+                    Escrow { key, owner: crate::ID, lamports: 1, data: vec![1; 8] }
+                }
+            })
+            .collect()
+    }
+
+    fn infos(escrows: &mut [Escrow]) -> Vec<AccountInfo<'_>> {
+        escrows
+            .iter_mut()
+            .map(|escrow| {
+                AccountInfo::new(
+                    &escrow.key,
+                    false,
+                    false,
+                    &mut escrow.lamports,
+                    &mut escrow.data,
+                    &escrow.owner,
+                    false,
+                    0,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn counts_only_escrows_that_were_not_swept() {
+        let fund = Pubkey::new_unique();
+        let mut escrows = escrows(&fund, 3, &[1]);
+
+        let open = count_open_dividends(&fund, 3, &infos(&mut escrows), &crate::ID);
+
+        assert_eq!(open.ok(), Some(2));
+    }
+
+    #[test]
+    fn every_escrow_must_be_passed_in_order() {
+        let fund = Pubkey::new_unique();
+        let mut escrows = escrows(&fund, 3, &[]);
+        let mut accounts = infos(&mut escrows);
+
+        let missing = count_open_dividends(&fund, 3, &accounts[..2], &crate::ID);
+        assert_eq!(missing.err(), Some(CustomError::DividendEscrowMismatch.into()));
+
+        accounts.swap(0, 1);
+        let reordered = count_open_dividends(&fund, 3, &accounts, &crate::ID);
+        assert!(reordered.is_err());
+    }
+}
@@ -1,11 +1,35 @@
+pub mod groups;
 pub mod fund_management;
 pub mod membership;
 pub mod contributions;
 pub mod trading;
 pub mod distribution;
+pub mod governance;
+pub mod timelock;
+pub mod multisig;
+pub mod redemptions;
+pub mod assets;
+pub mod allowlist;
+pub mod dividends;
+pub mod metadata;
+pub mod rewards;
+pub mod fundraising;
+pub mod migration;
 
+pub use groups::*;
 pub use fund_management::*;
 pub use membership::*;
 pub use contributions::*;
 pub use trading::*;
 pub use distribution::*;
+pub use governance::*;
+pub use timelock::*;
+pub use multisig::*;
+pub use redemptions::*;
+pub use assets::*;
+pub use allowlist::*;
+pub use dividends::*;
+pub use metadata::*;
+pub use rewards::*;
+pub use fundraising::*;
+pub use migration::*;
@@ -0,0 +1,239 @@
+use anchor_lang::prelude::*;
+use crate::constants::MAX_MULTISIG_SIGNERS;
+use crate::contexts::*;
+use crate::errors::ErrorCode as CustomError;
+use crate::events::MultisigTransactionExecuted;
+use crate::instructions::fund_management::{apply_fund_action, ActionOrigin};
+use crate::state::{Fund, FundAction, Multisig, MultisigAction, MultisigTransaction, ProposalAction};
+
+
+pub fn create_multisig(
+    ctx: Context<CreateMultisig>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    validate_signer_set(&signers, threshold)?;
+
+    let fund = &mut ctx.accounts.fund;
+    let multisig = &mut ctx.accounts.multisig;
+
+    multisig.fund = fund.key();
+    multisig.signers = signers;
+    multisig.threshold = threshold;
+    multisig.nonce = 0;
+    multisig.transaction_count = 0;
+    multisig.version = Multisig::VERSION;
+    multisig.bump = ctx.bumps.multisig;
+
+    // The previous single key keeps trading; every privileged action now
+    // needs `threshold` approvals.
+    fund.authority = multisig.key();
+
+    msg!(
+        "Fund authority moved to {}-of-{} multisig {}",
+        multisig.threshold,
+        multisig.signers.len(),
+        multisig.key()
+    );
+    Ok(())
+}
+
+
+pub fn propose_multisig_transaction(
+    ctx: Context<ProposeMultisigTransaction>,
+    action: MultisigAction,
+) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    let proposer_index = multisig
+        .signer_index(&ctx.accounts.proposer.key())
+        .ok_or(CustomError::NotMultisigSigner)?;
+
+    match &action {
+        MultisigAction::SetSigners { signers, threshold } => {
+            validate_signer_set(signers, *threshold)?;
+        }
+        // Fund actions are executed through `MultisigAction::Fund`.
+        MultisigAction::Approve(ProposalAction::Fund(_)) => {
+            return err!(CustomError::WrongProposalAction);
+        }
+        _ => {}
+    }
+
+    let transaction = &mut ctx.accounts.transaction;
+    transaction.multisig = multisig.key();
+    transaction.transaction_id = multisig.transaction_count;
+    transaction.nonce = multisig.nonce;
+    transaction.action = action;
+    transaction.approvals = vec![false; multisig.signers.len()];
+    transaction.approvals[proposer_index] = true;
+    transaction.approved_at = 0;
+    transaction.executed = false;
+    transaction.version = MultisigTransaction::VERSION;
+    transaction.bump = ctx.bumps.transaction;
+
+    if transaction.approval_count() >= multisig.threshold as usize {
+        transaction.approved_at = Clock::get()?.unix_timestamp;
+    }
+
+    multisig.transaction_count = multisig.transaction_count
+        .checked_add(1)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+
+    msg!("Multisig transaction {} proposed", transaction.transaction_id);
+    Ok(())
+}
+
+
+pub fn approve_multisig_transaction(ctx: Context<ApproveMultisigTransaction>) -> Result<()> {
+    let multisig = &ctx.accounts.multisig;
+    let transaction = &mut ctx.accounts.transaction;
+
+    require!(
+        !transaction.executed && transaction.nonce == multisig.nonce,
+        CustomError::MultisigTransactionInvalid
+    );
+
+    let signer_index = multisig
+        .signer_index(&ctx.accounts.signer.key())
+        .ok_or(CustomError::NotMultisigSigner)?;
+    transaction.approvals[signer_index] = true;
+
+    let approvals = transaction.approval_count();
+    if approvals >= multisig.threshold as usize && transaction.approved_at == 0 {
+        transaction.approved_at = Clock::get()?.unix_timestamp;
+    }
+
+    msg!(
+        "Multisig transaction {} approved ({}/{})",
+        transaction.transaction_id,
+        approvals,
+        multisig.threshold
+    );
+    Ok(())
+}
+
+
+pub fn execute_multisig_transaction(ctx: Context<ExecuteMultisigTransaction>) -> Result<()> {
+    let multisig = &ctx.accounts.multisig;
+    let transaction = &ctx.accounts.transaction;
+
+    multisig
+        .signer_index(&ctx.accounts.executor.key())
+        .ok_or(CustomError::NotMultisigSigner)?;
+    require!(
+        !transaction.executed && transaction.nonce == multisig.nonce,
+        CustomError::MultisigTransactionInvalid
+    );
+    require!(
+        transaction.approval_count() >= multisig.threshold as usize,
+        CustomError::NotEnoughApprovals
+    );
+
+    let action = transaction.action.clone();
+    match &action {
+        MultisigAction::Fund(fund_action) => {
+            // The timelock runs from the moment the threshold was reached.
+            let delay = ctx.accounts.fund.required_delay(fund_action);
+            let eta = transaction.approved_at
+                .checked_add(delay)
+                .ok_or(CustomError::ArithmeticOverflow)?;
+            require!(
+                Clock::get()?.unix_timestamp >= eta,
+                CustomError::TimelockNotElapsed
+            );
+
+            let origin = ActionOrigin::Multisig { waited: delay };
+            apply_fund_action(&mut ctx.accounts.fund, fund_action, origin)?;
+        }
+        MultisigAction::SetSigners { signers, threshold } => {
+            validate_signer_set(signers, *threshold)?;
+
+            let multisig = &mut ctx.accounts.multisig;
+            multisig.signers = signers.clone();
+            multisig.threshold = *threshold;
+            // Pending transactions were approved by the old set.
+            multisig.nonce = multisig.nonce
+                .checked_add(1)
+                .ok_or(CustomError::ArithmeticOverflow)?;
+        }
+        MultisigAction::Approve(_) => {
+            return err!(CustomError::MultisigApprovalNotExecutable);
+        }
+    }
+
+    ctx.accounts.transaction.executed = true;
+
+    emit!(MultisigTransactionExecuted {
+        fund: ctx.accounts.fund.key(),
+        multisig: ctx.accounts.multisig.key(),
+        transaction_id: ctx.accounts.transaction.transaction_id,
+    });
+
+    if action == MultisigAction::Fund(FundAction::CloseFund) {
+        ctx.accounts.fund.close(ctx.accounts.executor.to_account_info())?;
+    }
+
+    Ok(())
+}
+
+
+/// Marks a threshold-approved `Approve` transaction as executed on behalf of
+/// an instruction that carries out `expected` itself. `signer` must be one of
+/// the multisig's signers.
+pub(crate) fn consume_multisig_approval(
+    fund: &Account<Fund>,
+    multisig: &Account<Multisig>,
+    transaction: &mut Account<MultisigTransaction>,
+    signer: Pubkey,
+    expected: &ProposalAction,
+) -> Result<()> {
+    require!(
+        fund.authority == multisig.key() && multisig.fund == fund.key(),
+        CustomError::NotAuthorityOrApproved
+    );
+    require_keys_eq!(transaction.multisig, multisig.key(), CustomError::MultisigTransactionInvalid);
+    multisig
+        .signer_index(&signer)
+        .ok_or(CustomError::NotMultisigSigner)?;
+    require!(
+        !transaction.executed && transaction.nonce == multisig.nonce,
+        CustomError::MultisigTransactionInvalid
+    );
+    require!(
+        transaction.approval_count() >= multisig.threshold as usize,
+        CustomError::NotEnoughApprovals
+    );
+    require!(
+        transaction.action == MultisigAction::Approve(expected.clone()),
+        CustomError::WrongProposalAction
+    );
+
+    transaction.executed = true;
+
+    emit!(MultisigTransactionExecuted {
+        fund: fund.key(),
+        multisig: multisig.key(),
+        transaction_id: transaction.transaction_id,
+    });
+
+    Ok(())
+}
+
+
+fn validate_signer_set(signers: &[Pubkey], threshold: u8) -> Result<()> {
+    require!(
+        !signers.is_empty() && signers.len() <= MAX_MULTISIG_SIGNERS,
+        CustomError::InvalidMultisigConfig
+    );
+    require!(
+        threshold > 0 && threshold as usize <= signers.len(),
+        CustomError::InvalidMultisigConfig
+    );
+    for (index, signer) in signers.iter().enumerate() {
+        require!(
+            !signers[..index].contains(signer),
+            CustomError::InvalidMultisigConfig
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,248 @@
+use anchor_lang::prelude::*;
+use crate::constants::BPS_DENOMINATOR;
+use crate::contexts::*;
+use crate::errors::ErrorCode as CustomError;
+use crate::events::{
+    RedemptionEpochClosed, RedemptionSettled, WithdrawalCancelled, WithdrawalRequested,
+};
+use crate::state::{FundStatus, Member, RedemptionRequest};
+use crate::validation::validate_amount;
+
+
+pub fn request_withdrawal(ctx: Context<RequestWithdrawal>, shares: u64) -> Result<()> {
+    let fund = &mut ctx.accounts.fund;
+    let member = &mut ctx.accounts.member;
+
+    fund.require_status(&[FundStatus::Active])?;
+    require!(!fund.redemptions.settling, CustomError::RedemptionsSettling);
+    require!(
+        fund.redemptions.accepts_requests(Clock::get()?.unix_timestamp),
+        CustomError::RedemptionCutoffPassed
+    );
+    validate_amount(shares)?;
+    require!(
+        member.available_shares(Clock::get()?.unix_timestamp) >= shares,
+        CustomError::SharesLocked
+    );
+
+    let request = &mut ctx.accounts.request;
+    request.fund = fund.key();
+    request.wallet = member.wallet;
+    request.shares = shares;
+    request.epoch = fund.redemptions.epoch;
+    request.requested_at = Clock::get()?.unix_timestamp;
+    request.version = RedemptionRequest::VERSION;
+    request.bump = ctx.bumps.request;
+
+    member.queued_shares = member.queued_shares
+        .checked_add(shares)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+    fund.redemptions.queued_shares = fund.redemptions.queued_shares
+        .checked_add(shares)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+    fund.redemptions.pending_requests = fund.redemptions.pending_requests
+        .checked_add(1)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+
+    emit!(WithdrawalRequested {
+        fund: fund.key(),
+        wallet: member.wallet,
+        shares,
+        epoch: request.epoch,
+    });
+
+    msg!("Queued {} shares for redemption in epoch {}", shares, request.epoch);
+    Ok(())
+}
+
+
+pub fn cancel_withdrawal(ctx: Context<CancelWithdrawal>) -> Result<()> {
+    let fund = &mut ctx.accounts.fund;
+    let member = &mut ctx.accounts.member;
+    let request = &ctx.accounts.request;
+
+    fund.require_status(&[FundStatus::Active, FundStatus::Paused, FundStatus::WindingDown])?;
+    require!(
+        fund.redemptions.accepts_requests(Clock::get()?.unix_timestamp),
+        CustomError::RedemptionCutoffPassed
+    );
+
+    member.queued_shares = member.queued_shares
+        .checked_sub(request.shares)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+    fund.redemptions.queued_shares = fund.redemptions.queued_shares
+        .checked_sub(request.shares)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+    fund.redemptions.pending_requests = fund.redemptions.pending_requests
+        .checked_sub(1)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+
+    emit!(WithdrawalCancelled {
+        fund: fund.key(),
+        wallet: member.wallet,
+        shares: request.shares,
+        epoch: request.epoch,
+    });
+
+    msg!("Redemption request for {} shares cancelled", request.shares);
+    Ok(())
+}
+
+
+/// Settles queued redemptions once the epoch has ended. The first call closes
+/// the epoch at the current NAV and, if the withdrawal gate cannot cover the
+/// whole queue, fixes a pro-rata fill ratio. Requests are then paid in pages
+/// passed as `remaining_accounts` triples of (request, member, member wallet);
+/// unfilled remainders are carried into the next epoch, which opens once
+/// every request has been processed.
+pub fn process_redemptions<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ProcessRedemptions<'info>>,
+) -> Result<()> {
+    let fund_key = ctx.accounts.fund.key();
+    let now = Clock::get()?;
+
+    {
+        let fund = &mut ctx.accounts.fund;
+        fund.require_status(&[FundStatus::Active, FundStatus::WindingDown])?;
+        let ends_at = fund.redemptions.ends_at().ok_or(CustomError::ArithmeticOverflow)?;
+        require!(now.unix_timestamp >= ends_at, CustomError::EpochNotEnded);
+
+        if !fund.redemptions.settling {
+            fund.redemptions.settling = true;
+            fund.redemptions.closing_value = fund.total_value;
+            fund.redemptions.closing_shares = fund.total_shares;
+
+            let queued_value = (fund.redemptions.queued_shares as u128)
+                .checked_mul(fund.total_value as u128)
+                .ok_or(CustomError::ArithmeticOverflow)?
+                .checked_div(fund.total_shares.max(1) as u128)
+                .ok_or(CustomError::ArithmeticOverflow)?;
+            let nav = fund.total_value;
+            fund.withdrawal_gate.refresh(now.unix_timestamp, nav);
+            let capacity = fund.withdrawal_gate.remaining() as u128;
+            fund.redemptions.fill_bps = if queued_value <= capacity {
+                BPS_DENOMINATOR as u16
+            } else {
+                (capacity * BPS_DENOMINATOR / queued_value) as u16
+            };
+
+            emit!(RedemptionEpochClosed {
+                fund: fund_key,
+                epoch: fund.redemptions.epoch,
+                closing_value: fund.redemptions.closing_value,
+                closing_shares: fund.redemptions.closing_shares,
+                pending_requests: fund.redemptions.pending_requests,
+                fill_bps: fund.redemptions.fill_bps,
+            });
+        }
+    }
+
+    let pages = ctx.remaining_accounts.chunks_exact(3);
+    require!(pages.remainder().is_empty(), CustomError::InvalidRedemptionRequest);
+
+    for accounts in pages {
+        let mut request: Account<'info, RedemptionRequest> = Account::try_from(&accounts[0])?;
+        let mut member: Account<'info, Member> = Account::try_from(&accounts[1])?;
+        let member_wallet = &accounts[2];
+
+        let (expected_member, _) = Pubkey::find_program_address(
+            &[b"member", fund_key.as_ref(), request.wallet.as_ref()],
+            ctx.program_id,
+        );
+        require!(
+            request.fund == fund_key
+                && request.epoch == ctx.accounts.fund.redemptions.epoch
+                && member.key() == expected_member
+                && member_wallet.key() == request.wallet,
+            CustomError::InvalidRedemptionRequest
+        );
+
+        let fund = &mut ctx.accounts.fund;
+        let filled_shares = ((request.shares as u128)
+            * (fund.redemptions.fill_bps as u128)
+            / BPS_DENOMINATOR) as u64;
+        let carried_shares = request.shares - filled_shares;
+
+        let amount = (filled_shares as u128)
+            .checked_mul(fund.redemptions.closing_value as u128)
+            .ok_or(CustomError::ArithmeticOverflow)?
+            .checked_div(fund.redemptions.closing_shares as u128)
+            .ok_or(CustomError::ArithmeticOverflow)? as u64;
+
+        let fund_balance = fund.to_account_info().lamports();
+        require!(fund_balance >= amount, CustomError::InsufficientFunds);
+
+        **fund.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **member_wallet.try_borrow_mut_lamports()? += amount;
+
+        member.settle_rewards(fund.acc_reward_per_share);
+        member.shares = member.shares
+            .checked_sub(filled_shares)
+            .ok_or(CustomError::InsufficientShares)?;
+        member.queued_shares = member.queued_shares
+            .checked_sub(filled_shares)
+            .ok_or(CustomError::ArithmeticOverflow)?;
+        member.record_checkpoint(now.slot);
+        member.sync_reward_debt(fund.acc_reward_per_share);
+
+        fund.total_shares = fund.total_shares
+            .checked_sub(filled_shares)
+            .ok_or(CustomError::ArithmeticOverflow)?;
+        fund.total_value = fund.total_value
+            .checked_sub(amount)
+            .ok_or(CustomError::ArithmeticOverflow)?;
+        fund.withdrawal_gate.record(amount);
+        fund.redemptions.queued_shares = fund.redemptions.queued_shares
+            .checked_sub(filled_shares)
+            .ok_or(CustomError::ArithmeticOverflow)?;
+        fund.redemptions.pending_requests = fund.redemptions.pending_requests
+            .checked_sub(1)
+            .ok_or(CustomError::ArithmeticOverflow)?;
+
+        emit!(RedemptionSettled {
+            fund: fund_key,
+            wallet: request.wallet,
+            epoch: request.epoch,
+            shares: filled_shares,
+            amount,
+            carried_shares,
+        });
+
+        member.exit(ctx.program_id)?;
+        if carried_shares > 0 {
+            fund.redemptions.carried_requests = fund.redemptions.carried_requests
+                .checked_add(1)
+                .ok_or(CustomError::ArithmeticOverflow)?;
+            request.shares = carried_shares;
+            request.epoch = request.epoch
+                .checked_add(1)
+                .ok_or(CustomError::ArithmeticOverflow)?;
+            request.exit(ctx.program_id)?;
+        } else {
+            request.close(member_wallet.clone())?;
+        }
+    }
+
+    let fund = &mut ctx.accounts.fund;
+    if fund.redemptions.pending_requests == 0 {
+        msg!("Redemption epoch {} fully settled", fund.redemptions.epoch);
+        fund.redemptions.epoch = fund.redemptions.epoch
+            .checked_add(1)
+            .ok_or(CustomError::ArithmeticOverflow)?;
+        fund.redemptions.started_at = now.unix_timestamp;
+        fund.redemptions.settling = false;
+        fund.redemptions.closing_value = 0;
+        fund.redemptions.closing_shares = 0;
+        fund.redemptions.fill_bps = 0;
+        fund.redemptions.pending_requests = fund.redemptions.carried_requests;
+        fund.redemptions.carried_requests = 0;
+    } else {
+        msg!(
+            "{} redemption requests left in epoch {}",
+            fund.redemptions.pending_requests,
+            fund.redemptions.epoch
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use crate::constants::REWARD_PRECISION;
+use crate::contexts::*;
+use crate::errors::ErrorCode as CustomError;
+use crate::events::{RewardsClaimed, RewardsDeclared};
+use crate::instructions::fund_management::apply_immediately;
+use crate::state::{Fund, FundAction, FundStatus, Member};
+use crate::validation::validate_amount;
+
+
+/// Direct authority path. Multisig, timelocked and members-only funds
+/// declare through `FundAction::DeclareRewards` instead.
+pub fn declare_rewards(ctx: Context<DeclareRewards>, amount: u64) -> Result<()> {
+    apply_immediately(&mut ctx.accounts.fund, &FundAction::DeclareRewards { amount })
+}
+
+
+/// Sets `amount` aside from the fund's NAV as rewards for current holders,
+/// pro rata to shares. Members collect their part with `claim_rewards`.
+pub(crate) fn apply_declare_rewards(fund: &mut Account<Fund>, amount: u64) -> Result<()> {
+    validate_amount(amount)?;
+    fund.require_status(&[FundStatus::Active])?;
+    require!(fund.total_shares > 0, CustomError::InsufficientShares);
+    require!(amount <= fund.total_value, CustomError::InsufficientFunds);
+
+    let increment = (amount as u128)
+        .checked_mul(REWARD_PRECISION)
+        .ok_or(CustomError::ArithmeticOverflow)?
+        .checked_div(fund.total_shares as u128)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+
+    fund.acc_reward_per_share = fund.acc_reward_per_share
+        .checked_add(increment)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+    fund.reward_reserve = fund.reward_reserve
+        .checked_add(amount)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+    fund.total_value = fund.total_value
+        .checked_sub(amount)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+
+    emit!(RewardsDeclared {
+        fund: fund.key(),
+        amount,
+        acc_reward_per_share: fund.acc_reward_per_share,
+    });
+
+    msg!("Declared {} lamports of rewards over {} shares", amount, fund.total_shares);
+    Ok(())
+}
+
+
+pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
+    let fund = &mut ctx.accounts.fund;
+    fund.require_status(&[FundStatus::Active, FundStatus::WindingDown])?;
+    let amount = take_pending_rewards(fund, &mut ctx.accounts.member)?;
+    require!(amount > 0, CustomError::NoRewards);
+
+    let fund_balance = fund.to_account_info().lamports();
+    require!(fund_balance >= amount, CustomError::InsufficientFunds);
+
+    **fund.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.member_wallet.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    emit!(RewardsClaimed {
+        fund: fund.key(),
+        wallet: ctx.accounts.member_wallet.key(),
+        amount,
+    });
+
+    msg!("Claimed {} lamports of rewards", amount);
+    Ok(())
+}
+
+
+/// Settles the member and moves everything they are owed out of the reward
+/// reserve. The caller pays the returned amount.
+pub(crate) fn take_pending_rewards(fund: &mut Fund, member: &mut Member) -> Result<u64> {
+    member.settle_rewards(fund.acc_reward_per_share);
+    let amount = member.pending_rewards;
+    member.pending_rewards = 0;
+
+    fund.reward_reserve = fund.reward_reserve
+        .checked_sub(amount)
+        .ok_or(CustomError::InsufficientFunds)?;
+    Ok(amount)
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use crate::contexts::*;
+use crate::errors::ErrorCode as CustomError;
+use crate::events::{ActionCancelled, ActionExecuted, ActionQueued};
+use crate::instructions::fund_management::{apply_fund_action, ActionOrigin};
+use crate::state::{FundAction, QueuedAction};
+
+
+pub fn queue_action(ctx: Context<QueueAction>, action: FundAction) -> Result<()> {
+    let fund = &mut ctx.accounts.fund;
+    let queued_action = &mut ctx.accounts.queued_action;
+
+    let now = Clock::get()?.unix_timestamp;
+    let eta = now
+        .checked_add(fund.required_delay(&action))
+        .ok_or(CustomError::ArithmeticOverflow)?;
+
+    queued_action.fund = fund.key();
+    queued_action.action_id = fund.action_count;
+    queued_action.action = action.clone();
+    queued_action.queued_at = now;
+    queued_action.eta = eta;
+    queued_action.version = QueuedAction::VERSION;
+    queued_action.bump = ctx.bumps.queued_action;
+
+    fund.action_count = fund.action_count
+        .checked_add(1)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+
+    emit!(ActionQueued {
+        fund: fund.key(),
+        action_id: queued_action.action_id,
+        action,
+        eta,
+    });
+
+    msg!("Action {} queued, executable at {}", queued_action.action_id, eta);
+    Ok(())
+}
+
+
+pub fn execute_action(ctx: Context<ExecuteAction>) -> Result<()> {
+    let queued_action = &ctx.accounts.queued_action;
+
+    require!(
+        Clock::get()?.unix_timestamp >= queued_action.eta,
+        CustomError::TimelockNotElapsed
+    );
+
+    let action = queued_action.action.clone();
+    let origin = ActionOrigin::Timelock { waited: queued_action.delay() };
+    apply_fund_action(&mut ctx.accounts.fund, &action, origin)?;
+
+    emit!(ActionExecuted {
+        fund: ctx.accounts.fund.key(),
+        action_id: queued_action.action_id,
+        kind: action.kind(),
+    });
+
+    if action == FundAction::CloseFund {
+        ctx.accounts.fund.close(ctx.accounts.authority.to_account_info())?;
+    }
+
+    Ok(())
+}
+
+
+pub fn cancel_action(ctx: Context<CancelAction>) -> Result<()> {
+    let queued_action = &ctx.accounts.queued_action;
+
+    emit!(ActionCancelled {
+        fund: ctx.accounts.fund.key(),
+        action_id: queued_action.action_id,
+        kind: queued_action.action.kind(),
+    });
+
+    msg!("Action {} cancelled", queued_action.action_id);
+    Ok(())
+}
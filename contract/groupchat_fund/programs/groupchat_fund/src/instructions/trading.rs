@@ -1,6 +1,11 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::native_mint;
 use crate::contexts::*;
 use crate::errors::ErrorCode as CustomError;  // ✅ THIS LINE
+use crate::instructions::allowlist::check_allowed_mints;
+use crate::instructions::governance::consume_approval;
+use crate::state::{Fund, FundStatus, Proposal, ProposalAction};
+use crate::validation::validate_amount;
 
 
 pub fn record_swap(
@@ -10,13 +15,18 @@ pub fn record_swap(
     from_token: Pubkey,
     to_token: Pubkey,
 ) -> Result<()> {
+    validate_amount(amount_in)?;
+
     let fund = &mut ctx.accounts.fund;
 
-    require!(fund.is_active, CustomError::FundNotActive);
+    fund.require_status(&[FundStatus::Active, FundStatus::WindingDown])?;
     require!(
-        ctx.accounts.authority.key() == fund.authority,
+        ctx.accounts.authority.key() == fund.trader,
         CustomError::UnauthorizedTrader
     );
+    check_liquidation_trade(fund, to_token)?;
+    check_allowed_mints(&ctx.accounts.allowlist, &[from_token, to_token])?;
+    check_trade_limits(fund, ctx.accounts.proposal.as_mut(), from_token, to_token, amount_in)?;
 
     let old_value = fund.total_value;
 
@@ -53,14 +63,19 @@ pub fn execute_trade_mock(
     amount: u64,
     minimum_out: u64,
 ) -> Result<()> {
+    validate_amount(amount)?;
+
     let fund = &mut ctx.accounts.fund;
 
-    require!(fund.is_active, CustomError::FundNotActive);
+    fund.require_status(&[FundStatus::Active, FundStatus::WindingDown])?;
     require!(
-        ctx.accounts.authority.key() == fund.authority,
+        ctx.accounts.authority.key() == fund.trader,
         CustomError::UnauthorizedTrader
     );
+    check_liquidation_trade(fund, to_token)?;
+    check_allowed_mints(&ctx.accounts.allowlist, &[from_token, to_token])?;
     require!(amount <= fund.total_value, CustomError::InsufficientFunds);
+    check_trade_limits(fund, ctx.accounts.proposal.as_mut(), from_token, to_token, amount)?;
 
     let old_value = fund.total_value;
     
@@ -91,23 +106,34 @@ pub fn execute_trade_mock(
 
 pub fn execute_jupiter_swap(
     ctx: Context<ExecuteJupiterSwap>,
+    input_mint: Pubkey,
+    output_mint: Pubkey,
     amount_in: u64,
     minimum_amount_out: u64,
 ) -> Result<()> {
-    let fund = &ctx.accounts.fund;
+    validate_amount(amount_in)?;
 
-    require!(fund.is_active, CustomError::FundNotActive);
+    let fund = &mut ctx.accounts.fund;
+
+    fund.require_status(&[FundStatus::Active, FundStatus::WindingDown])?;
     require!(
-        ctx.accounts.authority.key() == fund.authority,
+        ctx.accounts.authority.key() == fund.trader,
         CustomError::UnauthorizedTrader
     );
+    // The swap route is not known on-chain yet, so liquidation goes through
+    // `record_swap`.
+    require!(fund.status != FundStatus::WindingDown, CustomError::LiquidationOnly);
+    check_allowed_mints(&ctx.accounts.allowlist, &[input_mint, output_mint])?;
 
     let fund_sol_balance = fund.to_account_info().lamports();
     require!(amount_in <= fund_sol_balance, CustomError::InsufficientFunds);
+    check_trade_limits(fund, ctx.accounts.proposal.as_mut(), input_mint, output_mint, amount_in)?;
 
     msg!("========================================");
     msg!("EXECUTING JUPITER SWAP VIA CPI");
     msg!("========================================");
+    msg!("Input Mint: {:?}", input_mint);
+    msg!("Output Mint: {:?}", output_mint);
     msg!("Amount In: {} lamports", amount_in);
     msg!("Minimum Out: {} tokens", minimum_amount_out);
     msg!("⚠️ Jupiter CPI integration required");
@@ -115,4 +141,53 @@ pub fn execute_jupiter_swap(
     msg!("========================================");
 
     Ok(())
-}
\ No newline at end of file
+}
+
+
+/// While winding down, the trader may only sell positions back into SOL.
+fn check_liquidation_trade(fund: &Fund, to_token: Pubkey) -> Result<()> {
+    require!(
+        fund.status != FundStatus::WindingDown || to_token == native_mint::ID,
+        CustomError::LiquidationOnly
+    );
+    Ok(())
+}
+
+
+/// Trades above the fund's per-trade or daily limit need a passed proposal
+/// approving this exact trade; the trader's signature alone is not enough.
+/// Approved trades still count towards the day's volume.
+fn check_trade_limits(
+    fund: &mut Account<Fund>,
+    proposal: Option<&mut Account<Proposal>>,
+    from_token: Pubkey,
+    to_token: Pubkey,
+    amount: u64,
+) -> Result<()> {
+    let nav = fund.total_value;
+    fund.trade_limits.refresh(Clock::get()?.unix_timestamp);
+
+    if fund.trade_limits.is_exceeded_by(amount, nav) {
+        match proposal {
+            Some(proposal) => consume_approval(
+                fund.key(),
+                proposal,
+                &ProposalAction::OverLimitTrade { from_token, to_token, amount },
+            )?,
+            None => {
+                msg!(
+                    "Trade of {} exceeds limits ({} bps of NAV {}, {} of {} used today)",
+                    amount,
+                    fund.trade_limits.max_trade_bps,
+                    nav,
+                    fund.trade_limits.window_volume,
+                    fund.trade_limits.max_daily_volume
+                );
+                return err!(CustomError::TradeLimitExceeded);
+            }
+        }
+    }
+
+    fund.trade_limits.record(amount);
+    Ok(())
+}
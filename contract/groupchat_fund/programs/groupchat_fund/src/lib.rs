@@ -2,12 +2,20 @@ use anchor_lang::prelude::*;
 
 pub mod constants;
 pub mod errors;
+pub mod events;
+pub mod validation;
 pub mod state;
 pub mod instructions;
 pub mod contexts;
+#[cfg(test)]
+mod test_utils;
 
 use contexts::*;
 use instructions::*;
+use state::{
+    DistributionKind, DividendTerms, FundAction, FundConfigUpdate, FundMetadataContent,
+    FundraisingParams, GroupPolicies, LockupPreview, MultisigAction, ProposalAction,
+};
 
 declare_id!("JDomJJbEK48FriJ5RVuTmgDGbNN8DLKAv33NdTydcWWd");
 
@@ -16,22 +24,62 @@ pub mod groupchat_fund {
     use super::*;
 
     // ========== Fund Management ==========
+    pub fn initialize_program_config(
+        ctx: Context<InitializeProgramConfig>,
+        registrar: Pubkey,
+    ) -> Result<()> {
+        instructions::groups::initialize_program_config(ctx, registrar)
+    }
+
+    pub fn set_registrar(ctx: Context<SetRegistrar>, registrar: Pubkey) -> Result<()> {
+        instructions::groups::set_registrar(ctx, registrar)
+    }
+
+    pub fn register_group(
+        ctx: Context<RegisterGroup>,
+        group_id: String,
+        group_hash: [u8; 32],
+        admins: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::groups::register_group(ctx, group_id, group_hash, admins)
+    }
+
+    pub fn set_group_admins(ctx: Context<UpdateGroup>, admins: Vec<Pubkey>) -> Result<()> {
+        instructions::groups::set_group_admins(ctx, admins)
+    }
+
+    pub fn set_group_policies(ctx: Context<UpdateGroup>, policies: GroupPolicies) -> Result<()> {
+        instructions::groups::set_group_policies(ctx, policies)
+    }
+
     pub fn initialize_fund(
         ctx: Context<InitializeFund>,
-        group_id: String,
         fund_name: String,
         min_contribution: u64,
         trading_fee_bps: u16,
+        fundraising: Option<FundraisingParams>,
     ) -> Result<()> {
         instructions::fund_management::initialize_fund(
             ctx, 
-            group_id, 
             fund_name, 
             min_contribution, 
-            trading_fee_bps
+            trading_fee_bps,
+            fundraising
         )
     }
 
+    pub fn migrate_group(ctx: Context<MigrateGroup>, admins: Vec<Pubkey>) -> Result<()> {
+        instructions::migration::migrate_group(ctx, admins)
+    }
+
+    pub fn migrate_fund(ctx: Context<MigrateFund>) -> Result<()> {
+        instructions::migration::migrate_fund(ctx)
+    }
+
+    pub fn migrate_member(ctx: Context<MigrateMember>) -> Result<()> {
+        instructions::migration::migrate_member(ctx)
+    }
+
     pub fn close_fund(ctx: Context<CloseFund>) -> Result<()> {
         instructions::fund_management::close_fund(ctx)
     }
@@ -44,11 +92,99 @@ pub mod groupchat_fund {
         instructions::fund_management::resume_fund(ctx)
     }
 
+    pub fn update_fund_config(
+        ctx: Context<UpdateFundConfig>,
+        update: FundConfigUpdate,
+    ) -> Result<()> {
+        instructions::fund_management::update_fund_config(ctx, update)
+    }
+
+    pub fn guardian_pause(ctx: Context<GuardianPause>, reason_code: u8) -> Result<()> {
+        instructions::fund_management::guardian_pause(ctx, reason_code)
+    }
+
+    pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        instructions::fund_management::propose_authority(ctx, new_authority)
+    }
+
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::fund_management::accept_authority(ctx)
+    }
+
+    pub fn cancel_authority_transfer(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+        instructions::fund_management::cancel_authority_transfer(ctx)
+    }
+
+    pub fn set_trade_limits(
+        ctx: Context<SetTradeLimits>,
+        max_trade_bps: u16,
+        max_daily_volume: u64,
+    ) -> Result<()> {
+        instructions::fund_management::set_trade_limits(ctx, max_trade_bps, max_daily_volume)
+    }
+
+    pub fn renounce_authority(ctx: Context<RenounceAuthority>) -> Result<()> {
+        instructions::fund_management::renounce_authority(ctx)
+    }
+
+    pub fn begin_wind_down(ctx: Context<BeginWindDown>) -> Result<()> {
+        instructions::fund_management::begin_wind_down(ctx)
+    }
+
+    // ========== Timelock ==========
+    pub fn queue_action(ctx: Context<QueueAction>, action: FundAction) -> Result<()> {
+        instructions::timelock::queue_action(ctx, action)
+    }
+
+    pub fn execute_action(ctx: Context<ExecuteAction>) -> Result<()> {
+        instructions::timelock::execute_action(ctx)
+    }
+
+    pub fn cancel_action(ctx: Context<CancelAction>) -> Result<()> {
+        instructions::timelock::cancel_action(ctx)
+    }
+
+    // ========== Multisig ==========
+    pub fn create_multisig(
+        ctx: Context<CreateMultisig>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::multisig::create_multisig(ctx, signers, threshold)
+    }
+
+    pub fn propose_multisig_transaction(
+        ctx: Context<ProposeMultisigTransaction>,
+        action: MultisigAction,
+    ) -> Result<()> {
+        instructions::multisig::propose_multisig_transaction(ctx, action)
+    }
+
+    pub fn approve_multisig_transaction(ctx: Context<ApproveMultisigTransaction>) -> Result<()> {
+        instructions::multisig::approve_multisig_transaction(ctx)
+    }
+
+    pub fn execute_multisig_transaction(ctx: Context<ExecuteMultisigTransaction>) -> Result<()> {
+        instructions::multisig::execute_multisig_transaction(ctx)
+    }
+
     // ========== Membership ==========
     pub fn add_member(ctx: Context<AddMember>, telegram_id: String) -> Result<()> {
         instructions::membership::add_member(ctx, telegram_id)
     }
 
+    pub fn preview_lockup(ctx: Context<PreviewLockup>) -> Result<LockupPreview> {
+        instructions::membership::preview_lockup(ctx)
+    }
+
+    pub fn delegate_vote(ctx: Context<DelegateVote>) -> Result<()> {
+        instructions::membership::delegate_vote(ctx)
+    }
+
+    pub fn revoke_delegation(ctx: Context<RevokeDelegation>) -> Result<()> {
+        instructions::membership::revoke_delegation(ctx)
+    }
+
     // ========== Contributions & Withdrawals ==========
     pub fn contribute(ctx: Context<Contribute>, amount: u64) -> Result<()> {
         instructions::contributions::contribute(ctx, amount)
@@ -58,7 +194,55 @@ pub mod groupchat_fund {
         instructions::contributions::withdraw(ctx, shares_to_burn)
     }
 
+    // ========== Fundraising ==========
+    pub fn finalize_fundraising(ctx: Context<FinalizeFundraising>) -> Result<()> {
+        instructions::fundraising::finalize_fundraising(ctx)
+    }
+
+    pub fn claim_refund(ctx: Context<ClaimRefund>) -> Result<()> {
+        instructions::fundraising::claim_refund(ctx)
+    }
+
+    // ========== Redemption Queue ==========
+    pub fn request_withdrawal(ctx: Context<RequestWithdrawal>, shares: u64) -> Result<()> {
+        instructions::redemptions::request_withdrawal(ctx, shares)
+    }
+
+    pub fn cancel_withdrawal(ctx: Context<CancelWithdrawal>) -> Result<()> {
+        instructions::redemptions::cancel_withdrawal(ctx)
+    }
+
+    pub fn process_redemptions<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ProcessRedemptions<'info>>,
+    ) -> Result<()> {
+        instructions::redemptions::process_redemptions(ctx)
+    }
+
+    // ========== Fund Assets ==========
+    pub fn register_fund_asset(ctx: Context<RegisterFundAsset>) -> Result<()> {
+        instructions::assets::register_fund_asset(ctx)
+    }
+
+    pub fn redeem_in_kind<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RedeemInKind<'info>>,
+        shares: u64,
+    ) -> Result<()> {
+        instructions::assets::redeem_in_kind(ctx, shares)
+    }
+
     // ========== Trading ==========
+    pub fn create_mint_allowlist(ctx: Context<CreateMintAllowlist>) -> Result<()> {
+        instructions::allowlist::create_mint_allowlist(ctx)
+    }
+
+    pub fn set_mint_allowed(
+        ctx: Context<SetMintAllowed>,
+        mint: Pubkey,
+        allowed: bool,
+    ) -> Result<()> {
+        instructions::allowlist::set_mint_allowed(ctx, mint, allowed)
+    }
+
     pub fn record_swap(
         ctx: Context<RecordSwap>,
         amount_in: u64,
@@ -93,11 +277,15 @@ pub mod groupchat_fund {
 
     pub fn execute_jupiter_swap(
         ctx: Context<ExecuteJupiterSwap>,
+        input_mint: Pubkey,
+        output_mint: Pubkey,
         amount_in: u64,
         minimum_amount_out: u64,
     ) -> Result<()> {
         instructions::trading::execute_jupiter_swap(
             ctx, 
+            input_mint, 
+            output_mint, 
             amount_in, 
             minimum_amount_out
         )
@@ -111,4 +299,76 @@ pub mod groupchat_fund {
     pub fn distribute_profits(ctx: Context<DistributeProfits>) -> Result<()> {
         instructions::distribution::distribute_profits(ctx)
     }
+
+    pub fn start_distribution_round(
+        ctx: Context<StartDistributionRound>,
+        kind: DistributionKind,
+    ) -> Result<()> {
+        instructions::distribution::start_distribution_round(ctx, kind)
+    }
+
+    pub fn process_distribution_round<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ProcessDistributionRound<'info>>,
+    ) -> Result<()> {
+        instructions::distribution::process_distribution_round(ctx)
+    }
+
+    // ========== Dividends ==========
+    pub fn declare_dividend(ctx: Context<DeclareDividend>, terms: DividendTerms) -> Result<()> {
+        instructions::dividends::declare_dividend(ctx, terms)
+    }
+
+    pub fn claim_dividend(
+        ctx: Context<ClaimDividend>,
+        index: u32,
+        amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::dividends::claim_dividend(ctx, index, amount, proof)
+    }
+
+    pub fn sweep_dividend(ctx: Context<SweepDividend>) -> Result<()> {
+        instructions::dividends::sweep_dividend(ctx)
+    }
+
+    // ========== Metadata ==========
+    pub fn create_fund_metadata(ctx: Context<CreateFundMetadata>) -> Result<()> {
+        instructions::metadata::create_fund_metadata(ctx)
+    }
+
+    pub fn update_fund_metadata(
+        ctx: Context<UpdateFundMetadata>,
+        content: FundMetadataContent,
+    ) -> Result<()> {
+        instructions::metadata::update_fund_metadata(ctx, content)
+    }
+
+    // ========== Rewards ==========
+    pub fn declare_rewards(ctx: Context<DeclareRewards>, amount: u64) -> Result<()> {
+        instructions::rewards::declare_rewards(ctx, amount)
+    }
+
+    pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
+        instructions::rewards::claim_rewards(ctx)
+    }
+
+    // ========== Governance ==========
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        description: String,
+        action: Option<ProposalAction>,
+    ) -> Result<()> {
+        instructions::governance::create_proposal(ctx, description, action)
+    }
+
+    pub fn cast_vote<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CastVote<'info>>,
+        approve: bool,
+    ) -> Result<()> {
+        instructions::governance::cast_vote(ctx, approve)
+    }
+
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        instructions::governance::execute_proposal(ctx)
+    }
 }
\ No newline at end of file
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::native_mint;
+use crate::constants::*;
+
+/// Mints the fund's trader may swap between. Starts from the protocol
+/// default list; changes need a passed proposal.
+#[account]
+pub struct MintAllowlist {
+    pub version: u8,
+    pub fund: Pubkey,
+    pub mints: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+impl MintAllowlist {
+    pub const VERSION: u8 = 1;
+
+    pub const SPACE: usize = DISCRIMINATOR
+        + 1
+        + 32
+        + (4 + 32 * MAX_ALLOWED_MINTS)
+        + 1;
+
+    /// SOL is the fund's base asset, so it is always tradable.
+    pub fn allows(&self, mint: &Pubkey) -> bool {
+        *mint == native_mint::ID || self.mints.contains(mint)
+    }
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+/// A non-SOL asset held by the fund in a program-owned vault.
+#[account]
+pub struct FundAsset {
+    pub version: u8,
+    pub fund: Pubkey,
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub bump: u8,
+}
+
+impl FundAsset {
+    pub const VERSION: u8 = 1;
+
+    pub const SPACE: usize = DISCRIMINATOR
+        + 1
+        + 32
+        + 32
+        + 32
+        + 1;
+}
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+/// Program-wide settings at the `["config"]` PDA, managed by the program's
+/// upgrade authority. `registrar` is the bot key that registers groups: it
+/// checks who administers a chat before a registry can be created for it.
+#[account]
+pub struct ProgramConfig {
+    pub version: u8,
+    pub registrar: Pubkey,
+    pub bump: u8,
+}
+
+impl ProgramConfig {
+    pub const VERSION: u8 = 1;
+
+    pub const SPACE: usize = DISCRIMINATOR
+        + 1
+        + 32
+        + 1;
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+/// What a distribution round pays out to each member.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DistributionKind {
+    /// Redeems every unlocked share, as `distribute_value` does.
+    Value,
+    /// Pays out profit above cost basis, as `distribute_profits` does.
+    Profits,
+    /// Wind-down payout of every share and pending reward, including
+    /// locked shares and inactive members.
+    Final,
+}
+
+/// Cursor for a fund-wide payout processed in pages by a permissionless
+/// crank. Only members registered before the round started take part.
+#[account]
+pub struct DistributionRound {
+    pub version: u8,
+    pub fund: Pubkey,
+    pub round_id: u64,
+    pub kind: DistributionKind,
+    pub started_at: i64,
+    pub member_count: u64,
+    pub processed: u64,
+    pub total_paid: u64,
+    pub completed: bool,
+    pub bump: u8,
+}
+
+impl DistributionRound {
+    pub const VERSION: u8 = 1;
+
+    pub const SPACE: usize = DISCRIMINATOR
+        + 1
+        + 32
+        + 8
+        + 1
+        + 8
+        + 8
+        + 8
+        + 8
+        + 1
+        + 1;
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+use crate::constants::*;
+
+/// Parameters of a Merkle dividend. Leaves are `(index, wallet, amount)`,
+/// with amounts computed off-chain from `Member::shares_at(snapshot_slot)`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DividendTerms {
+    pub merkle_root: [u8; 32],
+    pub total_amount: u64,
+    pub leaf_count: u32,
+    pub snapshot_slot: u64,
+    pub claim_deadline: i64,
+}
+
+impl DividendTerms {
+    pub const SPACE: usize = 32 + 8 + 4 + 8 + 8;
+}
+
+/// Escrow for a declared dividend. Holds the unclaimed lamports until they
+/// are claimed or swept back into the fund after the deadline.
+#[account]
+pub struct Dividend {
+    pub version: u8,
+    pub fund: Pubkey,
+    pub dividend_id: u64,
+    pub terms: DividendTerms,
+    pub claimed_amount: u64,
+    pub declared_by: Pubkey,
+    pub claimed: Vec<u8>,
+    pub bump: u8,
+}
+
+impl Dividend {
+    pub const VERSION: u8 = 1;
+
+    pub fn space(leaf_count: u32) -> usize {
+        DISCRIMINATOR
+            + 1
+            + 32
+            + 8
+            + DividendTerms::SPACE
+            + 8
+            + 32
+            + (4 + Self::bitmap_len(leaf_count))
+            + 1
+    }
+
+    pub fn bitmap_len(leaf_count: u32) -> usize {
+        (leaf_count as usize).div_ceil(8)
+    }
+
+    pub fn is_claimed(&self, index: u32) -> bool {
+        self.claimed[index as usize / 8] & (1 << (index % 8)) != 0
+    }
+
+    pub fn set_claimed(&mut self, index: u32) {
+        self.claimed[index as usize / 8] |= 1 << (index % 8);
+    }
+
+    /// Leaves and inner nodes use different prefixes so neither can be
+    /// passed off as the other.
+    pub fn leaf(index: u32, wallet: &Pubkey, amount: u64) -> [u8; 32] {
+        hashv(&[&[0u8][..], &index.to_le_bytes(), wallet.as_ref(), &amount.to_le_bytes()]).to_bytes()
+    }
+
+    /// Checks `leaf` against the root; sibling pairs are hashed in sorted
+    /// order, so the proof carries no left/right flags.
+    pub fn verify(&self, proof: &[[u8; 32]], leaf: [u8; 32]) -> bool {
+        let computed = proof.iter().fold(leaf, |node, sibling| {
+            let (left, right) = if node <= *sibling { (node, *sibling) } else { (*sibling, node) };
+            hashv(&[&[1u8][..], &left, &right]).to_bytes()
+        });
+        computed == self.terms.merkle_root
+    }
+}
@@ -1,28 +1,334 @@
 use anchor_lang::prelude::*;
 use crate::constants::*;
+use crate::errors::ErrorCode as CustomError;
+use crate::state::{ActionKind, FundAction, RedemptionEpoch, WithdrawalGate};
 
 #[account]
 pub struct Fund {
+    pub version: u8,
     pub authority: Pubkey,           
+    pub trader: Pubkey,
     pub group_id: String,            
     pub fund_name: String,           
     pub total_shares: u64,
     pub total_value: u64,
     pub min_contribution: u64,       
     pub trading_fee_bps: u16,       
-    pub is_active: bool,
+    pub status: FundStatus,
     pub bump: u8,                    
+    pub proposal_count: u64,
+    pub timelock_delays: [i64; MAX_ACTION_KINDS],
+    pub action_count: u64,
+    pub pending_authority: Option<Pubkey>,
+    pub members_only: bool,
+    pub guardian: Option<Pubkey>,
+    pub guardian_paused_at: i64,
+    pub config_policy: ConfigPolicy,
+    pub redemptions: RedemptionEpoch,
+    pub lockup_seconds: i64,
+    pub withdrawal_gate: WithdrawalGate,
+    pub asset_count: u16,
+    pub exit_fee_bps: u16,
+    pub member_count: u64,
+    pub round_count: u64,
+    pub active_round: Option<u64>,
+    pub dividend_count: u64,
+    pub acc_reward_per_share: u128,
+    pub reward_reserve: u64,
+    pub status_before_pause: FundStatus,
+    pub fundraising: Option<FundraisingParams>,
+    pub group_hash: [u8; 32],
+    pub fund_index: u64,
+    pub legacy_address: bool,
+    pub trade_limits: TradeLimits,
+    pub open_dividends: u32,
 }
 
 impl Fund {
+    pub const VERSION: u8 = 4;
+
     pub const SPACE: usize = DISCRIMINATOR 
+        + 1
         + 32
-        + (4 + MAX_STRING_LENGTH)
+        + 32
+        + (4 + MAX_GROUP_ID_LENGTH)
         + (4 + MAX_STRING_LENGTH)
         + 8
         + 8
         + 8
         + 2
         + 1
-        + 1;
-}
\ No newline at end of file
+        + 1
+        + 8
+        + 8 * MAX_ACTION_KINDS
+        + 8
+        + (1 + 32)
+        + 1
+        + (1 + 32)
+        + 8
+        + 1
+        + RedemptionEpoch::SPACE
+        + 8
+        + WithdrawalGate::SPACE
+        + 2
+        + 2
+        + 8
+        + 8
+        + (1 + 8)
+        + 8
+        + 16
+        + 8
+        + 1
+        + (1 + FundraisingParams::SPACE)
+        + 32
+        + 8
+        + 1
+        + TradeLimits::SPACE
+        + 4;
+
+    /// Every instruction that touches fund capital lists the statuses it
+    /// runs in.
+    pub fn require_status(&self, allowed: &[FundStatus]) -> Result<()> {
+        require!(allowed.contains(&self.status), CustomError::InvalidFundStatus);
+        Ok(())
+    }
+
+    /// PDA seeds for signing as the fund. Funds created before group
+    /// registries keep their original `group_id` address.
+    pub fn signer_seeds<'a>(&'a self, index: &'a [u8; 8], bump: &'a [u8; 1]) -> Vec<&'a [u8]> {
+        if self.legacy_address {
+            vec![b"fund", self.group_id.as_bytes(), bump]
+        } else {
+            vec![b"fund", &self.group_hash, index, bump]
+        }
+    }
+
+    pub fn has_address(&self, key: &Pubkey) -> bool {
+        let index = self.fund_index.to_le_bytes();
+        let bump = [self.bump];
+        Pubkey::create_program_address(&self.signer_seeds(&index, &bump), &crate::ID)
+            .is_ok_and(|address| address == *key)
+    }
+
+    pub fn timelock_delay(&self, kind: ActionKind) -> i64 {
+        self.timelock_delays[kind as usize]
+    }
+
+    /// How long `action` has to wait. Lowering a delay also waits out the
+    /// delay being lowered, so a timelock cannot be skipped by shrinking it
+    /// first.
+    pub fn required_delay(&self, action: &FundAction) -> i64 {
+        let delay = self.timelock_delay(action.kind());
+        match action {
+            FundAction::SetTimelockDelay { kind, delay: new_delay }
+                if *new_delay < self.timelock_delay(*kind) =>
+            {
+                delay.max(self.timelock_delay(*kind))
+            }
+            _ => delay,
+        }
+    }
+
+    /// Largest part of `shares` whose NAV value fits in what is left of the
+    /// current withdrawal-gate window.
+    pub fn gate_fillable_shares(&mut self, shares: u64, now: i64) -> u64 {
+        self.withdrawal_gate.refresh(now, self.total_value);
+        let remaining = self.withdrawal_gate.remaining();
+        if !self.withdrawal_gate.is_enabled() || self.total_shares == 0 {
+            return shares;
+        }
+
+        let value = (shares as u128) * (self.total_value as u128) / (self.total_shares as u128);
+        if value <= remaining as u128 {
+            shares
+        } else {
+            ((shares as u128) * (remaining as u128) / value) as u64
+        }
+    }
+
+    pub fn config(&self) -> FundConfig {
+        FundConfig {
+            min_contribution: self.min_contribution,
+            trading_fee_bps: self.trading_fee_bps,
+            fund_name: self.fund_name.clone(),
+            redemption_epoch_seconds: self.redemptions.duration,
+            lockup_seconds: self.lockup_seconds,
+            withdrawal_gate_bps: self.withdrawal_gate.gate_bps,
+            withdrawal_gate_window_seconds: self.withdrawal_gate.window_seconds,
+            exit_fee_bps: self.exit_fee_bps,
+        }
+    }
+}
+
+/// Lifecycle of a fund. Status only changes through `transition_status`,
+/// which checks `can_transition_to`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FundStatus {
+    Fundraising,
+    Active,
+    Paused,
+    WindingDown,
+    Closed,
+}
+
+impl FundStatus {
+    pub fn can_transition_to(self, to: FundStatus) -> bool {
+        use FundStatus::*;
+        matches!(
+            (self, to),
+            (Fundraising, Active | Paused | WindingDown | Closed)
+                | (Active, Paused | WindingDown | Closed)
+                | (Paused, Fundraising | Active | WindingDown | Closed)
+                | (WindingDown, Paused | Closed)
+        )
+    }
+}
+
+/// Terms of an initial raise. The fund trades once `target` is reached and
+/// refunds every contribution if the deadline passes first.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct FundraisingParams {
+    pub target: u64,
+    pub hard_cap: u64,
+    pub deadline: i64,
+}
+
+impl FundraisingParams {
+    pub const SPACE: usize = 8 + 8 + 8;
+}
+
+/// Risk limits on the trader. A zero limit is disabled. Volume is counted
+/// in lamports of `amount_in` over a rolling one-day window.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct TradeLimits {
+    pub max_trade_bps: u16,
+    pub max_daily_volume: u64,
+    pub window_started_at: i64,
+    pub window_volume: u64,
+}
+
+impl TradeLimits {
+    pub const SPACE: usize = 2 + 8 + 8 + 8;
+
+    pub fn refresh(&mut self, now: i64) {
+        let window_ends_at = self.window_started_at.saturating_add(TRADE_VOLUME_WINDOW_SECONDS);
+        if now >= window_ends_at {
+            self.window_started_at = now;
+            self.window_volume = 0;
+        }
+    }
+
+    /// Whether a trade of `amount` is larger than `max_trade_bps` of `nav`
+    /// or would take the window past `max_daily_volume`.
+    pub fn is_exceeded_by(&self, amount: u64, nav: u64) -> bool {
+        let over_trade_size = self.max_trade_bps > 0
+            && (amount as u128) * BPS_DENOMINATOR > (nav as u128) * (self.max_trade_bps as u128);
+        let over_daily_volume = self.max_daily_volume > 0
+            && self.window_volume.saturating_add(amount) > self.max_daily_volume;
+        over_trade_size || over_daily_volume
+    }
+
+    pub fn record(&mut self, amount: u64) {
+        self.window_volume = self.window_volume.saturating_add(amount);
+    }
+
+    /// Whether the new limits would let through a trade the current ones
+    /// stop: a limit raised, or switched off by setting it to zero.
+    pub fn is_loosened_by(&self, max_trade_bps: u16, max_daily_volume: u64) -> bool {
+        fn looser(current: u64, new: u64) -> bool {
+            current > 0 && (new == 0 || new > current)
+        }
+        looser(self.max_trade_bps.into(), max_trade_bps.into())
+            || looser(self.max_daily_volume, max_daily_volume)
+    }
+}
+
+/// Who may change the fund's configuration.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ConfigPolicy {
+    AuthorityOnly,
+    AuthorityWithTimelock,
+    Governance,
+}
+
+/// Snapshot of the governable fund parameters.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct FundConfig {
+    pub min_contribution: u64,
+    pub trading_fee_bps: u16,
+    pub fund_name: String,
+    pub redemption_epoch_seconds: i64,
+    pub lockup_seconds: i64,
+    pub withdrawal_gate_bps: u16,
+    pub withdrawal_gate_window_seconds: i64,
+    pub exit_fee_bps: u16,
+}
+
+/// Partial configuration change; `None` leaves a field untouched.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct FundConfigUpdate {
+    pub min_contribution: Option<u64>,
+    pub trading_fee_bps: Option<u16>,
+    pub fund_name: Option<String>,
+    pub redemption_epoch_seconds: Option<i64>,
+    pub lockup_seconds: Option<i64>,
+    pub withdrawal_gate_bps: Option<u16>,
+    pub withdrawal_gate_window_seconds: Option<i64>,
+    pub exit_fee_bps: Option<u16>,
+}
+
+impl FundConfigUpdate {
+    pub const SPACE: usize = (1 + 8)
+        + (1 + 2)
+        + (1 + 4 + MAX_STRING_LENGTH)
+        + (1 + 8)
+        + (1 + 8)
+        + (1 + 2)
+        + (1 + 8)
+        + (1 + 2);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::fund;
+
+    #[test]
+    fn raising_or_disabling_a_limit_loosens_it() {
+        let limits = TradeLimits {
+            max_trade_bps: 500,
+            max_daily_volume: 1_000,
+            ..TradeLimits::default()
+        };
+
+        assert!(limits.is_loosened_by(501, 1_000));
+        assert!(limits.is_loosened_by(0, 1_000));
+        assert!(limits.is_loosened_by(500, 1_001));
+        assert!(limits.is_loosened_by(500, 0));
+        assert!(!limits.is_loosened_by(500, 1_000));
+        assert!(!limits.is_loosened_by(100, 10));
+    }
+
+    #[test]
+    fn enabling_a_disabled_limit_tightens_it() {
+        let limits = TradeLimits::default();
+
+        assert!(!limits.is_loosened_by(500, 1_000));
+        assert!(!limits.is_loosened_by(0, 0));
+    }
+
+    #[test]
+    fn longest_group_id_and_name_fit_the_account() {
+        let mut fund = fund();
+        fund.group_id = "g".repeat(MAX_GROUP_ID_LENGTH);
+        fund.fund_name = "n".repeat(MAX_STRING_LENGTH);
+        fund.pending_authority = Some(Pubkey::new_unique());
+        fund.guardian = Some(Pubkey::new_unique());
+        fund.active_round = Some(0);
+        fund.fundraising = Some(FundraisingParams { target: 1, hard_cap: 1, deadline: 1 });
+
+        let mut data = Vec::new();
+        fund.try_serialize(&mut data).unwrap();
+        assert_eq!(data.len(), Fund::SPACE);
+    }
+}
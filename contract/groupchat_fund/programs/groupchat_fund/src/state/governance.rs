@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::state::{DistributionKind, DividendTerms, FundAction};
+
+/// What a passed proposal authorizes. `Fund` actions are applied by
+/// `execute_proposal`; the rest are approvals consumed by the instruction
+/// that needs them, since it brings accounts `execute_proposal` does not have.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum ProposalAction {
+    Fund(FundAction),
+    StartDistributionRound { kind: DistributionKind },
+    DeclareDividend(DividendTerms),
+    UpdateMetadata { content_hash: [u8; 32] },
+    SetMintAllowed { mint: Pubkey, allowed: bool },
+    OverLimitTrade { from_token: Pubkey, to_token: Pubkey, amount: u64 },
+}
+
+impl ProposalAction {
+    pub const SPACE: usize = 1 + FundAction::SPACE;
+}
+
+#[account]
+pub struct Proposal {
+    pub version: u8,
+    pub fund: Pubkey,
+    pub proposer: Pubkey,
+    pub proposal_id: u64,
+    pub description: String,
+    pub created_slot: u64,
+    pub voting_ends_at: i64,
+    pub total_shares: u64,
+    pub yes_shares: u64,
+    pub no_shares: u64,
+    pub action: Option<ProposalAction>,
+    pub executed: bool,
+    pub bump: u8,
+}
+
+impl Proposal {
+    pub const VERSION: u8 = 1;
+
+    pub const SPACE: usize = DISCRIMINATOR
+        + 1
+        + 32
+        + 32
+        + 8
+        + (4 + MAX_DESCRIPTION_LENGTH)
+        + 8
+        + 8
+        + 8
+        + 8
+        + 8
+        + (1 + ProposalAction::SPACE)
+        + 1
+        + 1;
+
+    /// Slot whose balances carry voting weight: the one before creation, so
+    /// shares bought in the proposal's own slot (or transaction) do not count.
+    pub fn snapshot_slot(&self) -> u64 {
+        self.created_slot.saturating_sub(1)
+    }
+
+    /// Majority of votes cast, with turnout of at least `QUORUM_BPS` of the
+    /// shares outstanding when the proposal was created.
+    pub fn has_passed(&self) -> bool {
+        let turnout = self.yes_shares as u128 + self.no_shares as u128;
+        self.yes_shares > self.no_shares
+            && turnout * BPS_DENOMINATOR >= self.total_shares as u128 * QUORUM_BPS
+    }
+}
+
+#[account]
+pub struct VoteRecord {
+    pub version: u8,
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub approve: bool,
+    pub shares: u64,
+    pub delegated_shares: u64,
+    pub bump: u8,
+}
+
+impl VoteRecord {
+    pub const VERSION: u8 = 1;
+
+    pub const SPACE: usize = DISCRIMINATOR
+        + 1
+        + 32
+        + 32
+        + 1
+        + 8
+        + 8
+        + 1;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::member;
+
+    fn proposal(created_slot: u64) -> Proposal {
+        Proposal {
+            version: Proposal::VERSION,
+            fund: Pubkey::default(),
+            proposer: Pubkey::default(),
+            proposal_id: 0,
+            description: String::new(),
+            created_slot,
+            voting_ends_at: 0,
+            total_shares: 0,
+            yes_shares: 0,
+            no_shares: 0,
+            action: None,
+            executed: false,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn contribution_in_creation_slot_carries_no_weight() {
+        let mut member = member();
+        member.shares = 1_000;
+        member.record_checkpoint(100);
+
+        let proposal = proposal(100);
+        assert_eq!(member.shares_at(proposal.snapshot_slot()), 0);
+    }
+
+    #[test]
+    fn contribution_before_creation_slot_carries_weight() {
+        let mut member = member();
+        member.shares = 1_000;
+        member.record_checkpoint(99);
+
+        let proposal = proposal(100);
+        assert_eq!(member.shares_at(proposal.snapshot_slot()), 1_000);
+    }
+
+    #[test]
+    fn delegation_in_creation_slot_is_not_settled() {
+        let mut member = member();
+        member.delegation_slot = 100;
+
+        assert!(!member.delegation_settled_at(proposal(100).snapshot_slot()));
+        assert!(member.delegation_settled_at(proposal(101).snapshot_slot()));
+    }
+}
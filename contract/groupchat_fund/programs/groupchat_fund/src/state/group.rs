@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hash;
+use crate::constants::*;
+use crate::state::ConfigPolicy;
+
+/// Registry for one Telegram group. Funds are addressed by the hash of the
+/// group id plus `fund_count` at creation, so ids of any length work and a
+/// group can run any number of funds.
+#[account]
+pub struct Group {
+    pub version: u8,
+    pub group_hash: [u8; 32],
+    pub group_id: String,
+    pub fund_count: u64,
+    pub bump: u8,
+    pub admins: Vec<Pubkey>,
+    pub default_policies: GroupPolicies,
+    pub funds: Vec<Pubkey>,
+}
+
+impl Group {
+    pub const VERSION: u8 = 2;
+
+    /// The account grows by one key for every fund created in the group.
+    pub fn space(fund_count: usize) -> usize {
+        DISCRIMINATOR
+            + 1
+            + 32
+            + (4 + MAX_GROUP_ID_LENGTH)
+            + 8
+            + 1
+            + (4 + 32 * MAX_GROUP_ADMINS)
+            + GroupPolicies::SPACE
+            + (4 + 32 * fund_count)
+    }
+
+    pub fn hash_id(group_id: &str) -> [u8; 32] {
+        hash(group_id.as_bytes()).to_bytes()
+    }
+
+    pub fn has_address(&self, key: &Pubkey) -> bool {
+        Pubkey::create_program_address(&[b"group", &self.group_hash, &[self.bump]], &crate::ID)
+            .is_ok_and(|address| address == *key)
+    }
+
+    pub fn is_admin(&self, key: &Pubkey) -> bool {
+        self.admins.contains(key)
+    }
+}
+
+/// Settings every new fund in the group starts with.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct GroupPolicies {
+    pub members_only: bool,
+    pub config_policy: ConfigPolicy,
+    pub lockup_seconds: i64,
+    pub exit_fee_bps: u16,
+}
+
+impl GroupPolicies {
+    pub const SPACE: usize = 1 + 1 + 8 + 2;
+}
+
+impl Default for GroupPolicies {
+    fn default() -> Self {
+        Self {
+            members_only: false,
+            config_policy: ConfigPolicy::AuthorityOnly,
+            lockup_seconds: 0,
+            exit_fee_bps: 0,
+        }
+    }
+}
@@ -3,18 +3,176 @@ use crate::constants::*;
 
 #[account]
 pub struct Member {
+    pub version: u8,
     pub wallet: Pubkey,              
     pub telegram_id: String,
     pub shares: u64,                 
     pub total_contributed: u64,
     pub is_active: bool,
+    pub checkpoints: Vec<ShareCheckpoint>,
+    pub delegate: Option<Pubkey>,
+    pub delegation_slot: u64,
+    pub delegator_count: u32,
+    pub queued_shares: u64,
+    pub lots: Vec<ShareLot>,
+    pub joined_round: u64,
+    pub last_paid_round: Option<u64>,
+    pub reward_debt: u128,
+    pub pending_rewards: u64,
+}
+
+/// Share balance of a member as of a given slot.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct ShareCheckpoint {
+    pub slot: u64,
+    pub shares: u64,
+}
+
+impl ShareCheckpoint {
+    pub const SPACE: usize = 8 + 8;
+}
+
+/// Shares minted by one contribution that stay locked until `unlock_at`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct ShareLot {
+    pub shares: u64,
+    pub unlock_at: i64,
+}
+
+impl ShareLot {
+    pub const SPACE: usize = 8 + 8;
+}
+
+/// Returned by `preview_lockup`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct LockupPreview {
+    pub shares: u64,
+    pub locked_shares: u64,
+    pub unlocked_shares: u64,
+    pub queued_shares: u64,
+    pub next_unlock_at: Option<i64>,
 }
 
 impl Member {
+    pub const VERSION: u8 = 1;
+
     pub const SPACE: usize = DISCRIMINATOR 
+        + 1
         + 32                                    
         + (4 + MAX_STRING_LENGTH)
         + 8                                     
         + 8                                     
-        + 1;
+        + 1
+        + (4 + MAX_CHECKPOINTS * ShareCheckpoint::SPACE)
+        + (1 + 32)
+        + 8
+        + 4
+        + 8
+        + (4 + MAX_SHARE_LOTS * ShareLot::SPACE)
+        + 8
+        + (1 + 8)
+        + 16
+        + 8;
+
+    /// Shares that are neither in a lockup nor queued for redemption.
+    pub fn available_shares(&self, now: i64) -> u64 {
+        self.shares
+            .saturating_sub(self.locked_shares(now))
+            .saturating_sub(self.queued_shares)
+    }
+
+    pub fn locked_shares(&self, now: i64) -> u64 {
+        self.lots
+            .iter()
+            .filter(|lot| lot.unlock_at > now)
+            .map(|lot| lot.shares)
+            .sum()
+    }
+
+    /// Adds a locked lot, dropping expired lots first. Once the list is full
+    /// the new shares join the latest lot and inherit the later unlock time.
+    pub fn add_lot(&mut self, shares: u64, unlock_at: i64, now: i64) {
+        self.lots.retain(|lot| lot.unlock_at > now);
+
+        if self.lots.len() >= MAX_SHARE_LOTS {
+            if let Some(last) = self.lots.last_mut() {
+                last.shares = last.shares.saturating_add(shares);
+                last.unlock_at = last.unlock_at.max(unlock_at);
+            }
+            return;
+        }
+        self.lots.push(ShareLot { shares, unlock_at });
+    }
+
+    pub fn lockup_preview(&self, now: i64) -> LockupPreview {
+        let locked_shares = self.locked_shares(now);
+        LockupPreview {
+            shares: self.shares,
+            locked_shares,
+            unlocked_shares: self.shares.saturating_sub(locked_shares),
+            queued_shares: self.queued_shares,
+            next_unlock_at: self
+                .lots
+                .iter()
+                .filter(|lot| lot.unlock_at > now)
+                .map(|lot| lot.unlock_at)
+                .min(),
+        }
+    }
+
+    /// Moves rewards accrued since the last settlement into
+    /// `pending_rewards`. Call before the share balance changes and
+    /// `sync_reward_debt` after it.
+    pub fn settle_rewards(&mut self, acc_reward_per_share: u128) {
+        let accrued = self.accrued_rewards(acc_reward_per_share);
+        let owed = accrued.saturating_sub(self.reward_debt) as u64;
+        self.pending_rewards = self.pending_rewards.saturating_add(owed);
+        self.reward_debt = accrued;
+    }
+
+    pub fn sync_reward_debt(&mut self, acc_reward_per_share: u128) {
+        self.reward_debt = self.accrued_rewards(acc_reward_per_share);
+    }
+
+    fn accrued_rewards(&self, acc_reward_per_share: u128) -> u128 {
+        (self.shares as u128).saturating_mul(acc_reward_per_share) / REWARD_PRECISION
+    }
+
+    /// Records the current share balance at `slot`. Several changes within one
+    /// slot collapse into a single checkpoint, and the oldest checkpoint is
+    /// dropped once the history is full.
+    pub fn record_checkpoint(&mut self, slot: u64) {
+        if let Some(last) = self.checkpoints.last_mut() {
+            if last.slot == slot {
+                last.shares = self.shares;
+                return;
+            }
+            if last.shares == self.shares {
+                return;
+            }
+        }
+
+        if self.checkpoints.len() >= MAX_CHECKPOINTS {
+            self.checkpoints.remove(0);
+        }
+        self.checkpoints.push(ShareCheckpoint { slot, shares: self.shares });
+    }
+
+    /// Share balance as of `slot`. Slots older than the retained history
+    /// count as zero so that votes never use a balance we cannot prove.
+    pub fn shares_at(&self, slot: u64) -> u64 {
+        self.checkpoints
+            .iter()
+            .rev()
+            .find(|checkpoint| checkpoint.slot <= slot)
+            .map(|checkpoint| checkpoint.shares)
+            .unwrap_or(0)
+    }
+
+    /// Whether the member's delegation state is known to be unchanged since
+    /// `slot`. Any change after a proposal was created forfeits the member's
+    /// weight on that proposal, which keeps it from being counted twice.
+    pub fn delegation_settled_at(&self, slot: u64) -> bool {
+        self.delegation_slot <= slot
+    }
 }
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hash;
+use crate::constants::*;
+
+/// Descriptive content shown by the bot and web UI.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, Default)]
+pub struct FundMetadataContent {
+    pub description: String,
+    pub strategy: String,
+    pub uri: String,
+    pub image_uri: String,
+    pub risk_disclosures: String,
+}
+
+impl FundMetadataContent {
+    /// Serialized size, which the metadata account is resized to.
+    pub fn serialized_len(&self) -> usize {
+        (4 + self.description.len())
+            + (4 + self.strategy.len())
+            + (4 + self.uri.len())
+            + (4 + self.image_uri.len())
+            + (4 + self.risk_disclosures.len())
+    }
+
+    pub fn is_within_limits(&self) -> bool {
+        self.description.len() <= MAX_METADATA_TEXT_LENGTH
+            && self.strategy.len() <= MAX_METADATA_TEXT_LENGTH
+            && self.uri.len() <= MAX_METADATA_URI_LENGTH
+            && self.image_uri.len() <= MAX_METADATA_URI_LENGTH
+            && self.risk_disclosures.len() <= MAX_RISK_DISCLOSURE_LENGTH
+    }
+
+    /// Proposals approve a content hash, since the content itself does not
+    /// fit in a proposal account.
+    pub fn content_hash(&self) -> Result<[u8; 32]> {
+        Ok(hash(&self.try_to_vec()?).to_bytes())
+    }
+}
+
+#[account]
+pub struct FundMetadata {
+    pub version: u8,
+    pub fund: Pubkey,
+    pub revision: u64,
+    pub updated_at: i64,
+    pub bump: u8,
+    pub content: FundMetadataContent,
+}
+
+impl FundMetadata {
+    pub const VERSION: u8 = 1;
+
+    pub fn space(content: &FundMetadataContent) -> usize {
+        DISCRIMINATOR
+            + 1
+            + 32
+            + 8
+            + 8
+            + 1
+            + content.serialized_len()
+    }
+}
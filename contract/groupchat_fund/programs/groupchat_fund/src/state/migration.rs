@@ -0,0 +1,622 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::ErrorCode as CustomError;
+use crate::state::{
+    ConfigPolicy, Fund, FundStatus, FundraisingParams, Group, GroupPolicies, Member,
+    RedemptionEpoch, ShareCheckpoint, TradeLimits, WithdrawalGate,
+};
+
+impl Group {
+    /// Reads a group stored in an earlier layout and upgrades it with
+    /// `admins`, returning the version it was read from.
+    pub fn migrate_from(data: &[u8], admins: Vec<Pubkey>) -> Result<(u8, Group)> {
+        require!(data.starts_with(Group::DISCRIMINATOR), CustomError::UnknownAccountLayout);
+        let mut body = &data[DISCRIMINATOR..];
+
+        match body.first().copied() {
+            Some(1) => Ok((1, GroupV1::deserialize(&mut body)?.migrate(admins))),
+            Some(Group::VERSION) => err!(CustomError::AlreadyMigrated),
+            _ => err!(CustomError::UnknownAccountLayout),
+        }
+    }
+}
+
+impl Fund {
+    /// Reads a fund stored in an earlier layout and upgrades it, returning
+    /// the version it was read from. Versioned layouts are told apart by
+    /// their version byte; the first layout had none and is matched by size.
+    pub fn migrate_from(data: &[u8], now: i64) -> Result<(u8, Fund)> {
+        require!(data.starts_with(Fund::DISCRIMINATOR), CustomError::UnknownAccountLayout);
+        let mut body = &data[DISCRIMINATOR..];
+
+        if data.len() == FundV0::SPACE {
+            return Ok((0, FundV0::deserialize(&mut body)?.migrate(now).migrate().migrate().migrate()));
+        }
+        match body.first().copied() {
+            Some(1) => Ok((1, FundV1::deserialize(&mut body)?.migrate().migrate().migrate())),
+            Some(2) => Ok((2, FundV2::deserialize(&mut body)?.migrate().migrate())),
+            Some(3) => Ok((3, FundV3::deserialize(&mut body)?.migrate())),
+            Some(Fund::VERSION) => err!(CustomError::AlreadyMigrated),
+            _ => err!(CustomError::UnknownAccountLayout),
+        }
+    }
+}
+
+impl Member {
+    /// Member counterpart of `Fund::migrate_from`.
+    pub fn migrate_from(data: &[u8], fund: &Fund, slot: u64) -> Result<(u8, Member)> {
+        require!(data.starts_with(Member::DISCRIMINATOR), CustomError::UnknownAccountLayout);
+        let mut body = &data[DISCRIMINATOR..];
+
+        if data.len() == MemberV0::SPACE {
+            return Ok((0, MemberV0::deserialize(&mut body)?.migrate(fund, slot)));
+        }
+        match body.first().copied() {
+            Some(Member::VERSION) => err!(CustomError::AlreadyMigrated),
+            _ => err!(CustomError::UnknownAccountLayout),
+        }
+    }
+}
+
+/// `Group` version 1, from before groups had admins.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct GroupV1 {
+    pub version: u8,
+    pub group_hash: [u8; 32],
+    pub group_id: String,
+    pub fund_count: u64,
+    pub bump: u8,
+}
+
+impl GroupV1 {
+    /// The fund list is rebuilt from the addresses `initialize_fund` gave
+    /// each index.
+    pub fn migrate(self, admins: Vec<Pubkey>) -> Group {
+        let funds = (0..self.fund_count)
+            .map(|index| {
+                Pubkey::find_program_address(
+                    &[b"fund", &self.group_hash, &index.to_le_bytes()],
+                    &crate::ID,
+                )
+                .0
+            })
+            .collect();
+
+        Group {
+            version: Group::VERSION,
+            group_hash: self.group_hash,
+            group_id: self.group_id,
+            fund_count: self.fund_count,
+            bump: self.bump,
+            admins,
+            default_policies: GroupPolicies::default(),
+            funds,
+        }
+    }
+}
+
+/// `Fund` as first deployed, before accounts carried a version byte.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct FundV0 {
+    pub authority: Pubkey,
+    pub group_id: String,
+    pub fund_name: String,
+    pub total_shares: u64,
+    pub total_value: u64,
+    pub min_contribution: u64,
+    pub trading_fee_bps: u16,
+    pub is_active: bool,
+    pub bump: u8,
+}
+
+impl FundV0 {
+    pub const SPACE: usize = DISCRIMINATOR
+        + 32
+        + (4 + MAX_STRING_LENGTH)
+        + (4 + MAX_STRING_LENGTH)
+        + 8
+        + 8
+        + 8
+        + 2
+        + 1
+        + 1;
+
+    /// Carries the balances over and gives every newer field the value
+    /// `initialize_fund` would have set. Members are counted again as they
+    /// are migrated.
+    pub fn migrate(self, now: i64) -> FundV1 {
+        let status = if self.is_active { FundStatus::Active } else { FundStatus::Paused };
+
+        FundV1 {
+            version: 1,
+            authority: self.authority,
+            trader: self.authority,
+            group_id: self.group_id,
+            fund_name: self.fund_name,
+            total_shares: self.total_shares,
+            total_value: self.total_value,
+            min_contribution: self.min_contribution,
+            trading_fee_bps: self.trading_fee_bps,
+            status,
+            bump: self.bump,
+            proposal_count: 0,
+            timelock_delays: [0; MAX_ACTION_KINDS],
+            action_count: 0,
+            pending_authority: None,
+            members_only: false,
+            guardian: None,
+            guardian_paused_at: 0,
+            config_policy: ConfigPolicy::AuthorityOnly,
+            redemptions: RedemptionEpoch {
+                started_at: now,
+                duration: DEFAULT_REDEMPTION_EPOCH_SECONDS,
+                ..RedemptionEpoch::default()
+            },
+            lockup_seconds: 0,
+            withdrawal_gate: WithdrawalGate {
+                window_seconds: DEFAULT_GATE_WINDOW_SECONDS,
+                ..WithdrawalGate::default()
+            },
+            asset_count: 0,
+            exit_fee_bps: 0,
+            member_count: 0,
+            round_count: 0,
+            active_round: None,
+            dividend_count: 0,
+            acc_reward_per_share: 0,
+            reward_reserve: 0,
+            status_before_pause: FundStatus::Active,
+            fundraising: None,
+        }
+    }
+}
+
+/// `Fund` version 1, the first versioned layout, from before funds were
+/// registered under a group.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct FundV1 {
+    pub version: u8,
+    pub authority: Pubkey,
+    pub trader: Pubkey,
+    pub group_id: String,
+    pub fund_name: String,
+    pub total_shares: u64,
+    pub total_value: u64,
+    pub min_contribution: u64,
+    pub trading_fee_bps: u16,
+    pub status: FundStatus,
+    pub bump: u8,
+    pub proposal_count: u64,
+    pub timelock_delays: [i64; MAX_ACTION_KINDS],
+    pub action_count: u64,
+    pub pending_authority: Option<Pubkey>,
+    pub members_only: bool,
+    pub guardian: Option<Pubkey>,
+    pub guardian_paused_at: i64,
+    pub config_policy: ConfigPolicy,
+    pub redemptions: RedemptionEpoch,
+    pub lockup_seconds: i64,
+    pub withdrawal_gate: WithdrawalGate,
+    pub asset_count: u16,
+    pub exit_fee_bps: u16,
+    pub member_count: u64,
+    pub round_count: u64,
+    pub active_round: Option<u64>,
+    pub dividend_count: u64,
+    pub acc_reward_per_share: u128,
+    pub reward_reserve: u64,
+    pub status_before_pause: FundStatus,
+    pub fundraising: Option<FundraisingParams>,
+}
+
+impl FundV1 {
+    /// These funds were all created at the `["fund", group_id]` address,
+    /// which they keep.
+    pub fn migrate(self) -> FundV2 {
+        FundV2 {
+            group_hash: Group::hash_id(&self.group_id),
+            fund_index: 0,
+            legacy_address: true,
+            v1: FundV1 { version: 2, ..self },
+        }
+    }
+}
+
+/// `Fund` version 2, from before trade limits: the version 1 fields followed
+/// by the group registry ones.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct FundV2 {
+    pub v1: FundV1,
+    pub group_hash: [u8; 32],
+    pub fund_index: u64,
+    pub legacy_address: bool,
+}
+
+impl FundV2 {
+    /// Trade limits start disabled.
+    pub fn migrate(self) -> FundV3 {
+        FundV3 {
+            v2: FundV2 {
+                v1: FundV1 { version: 3, ..self.v1 },
+                ..self
+            },
+            trade_limits: TradeLimits::default(),
+        }
+    }
+}
+
+/// `Fund` version 3, from before dividend escrows were counted: the version 2
+/// fields followed by the trade limits.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct FundV3 {
+    pub v2: FundV2,
+    pub trade_limits: TradeLimits,
+}
+
+impl FundV3 {
+    /// The layout cannot say which dividend escrows are still open, so the
+    /// count starts at zero; `migrate_fund` counts them from the escrows.
+    pub fn migrate(self) -> Fund {
+        let FundV3 {
+            v2: FundV2 { v1, group_hash, fund_index, legacy_address },
+            trade_limits,
+        } = self;
+
+        Fund {
+            version: Fund::VERSION,
+            authority: v1.authority,
+            trader: v1.trader,
+            group_id: v1.group_id,
+            fund_name: v1.fund_name,
+            total_shares: v1.total_shares,
+            total_value: v1.total_value,
+            min_contribution: v1.min_contribution,
+            trading_fee_bps: v1.trading_fee_bps,
+            status: v1.status,
+            bump: v1.bump,
+            proposal_count: v1.proposal_count,
+            timelock_delays: v1.timelock_delays,
+            action_count: v1.action_count,
+            pending_authority: v1.pending_authority,
+            members_only: v1.members_only,
+            guardian: v1.guardian,
+            guardian_paused_at: v1.guardian_paused_at,
+            config_policy: v1.config_policy,
+            redemptions: v1.redemptions,
+            lockup_seconds: v1.lockup_seconds,
+            withdrawal_gate: v1.withdrawal_gate,
+            asset_count: v1.asset_count,
+            exit_fee_bps: v1.exit_fee_bps,
+            member_count: v1.member_count,
+            round_count: v1.round_count,
+            active_round: v1.active_round,
+            dividend_count: v1.dividend_count,
+            acc_reward_per_share: v1.acc_reward_per_share,
+            reward_reserve: v1.reward_reserve,
+            status_before_pause: v1.status_before_pause,
+            fundraising: v1.fundraising,
+            group_hash,
+            fund_index,
+            legacy_address,
+            trade_limits,
+            open_dividends: 0,
+        }
+    }
+}
+
+/// `Member` as first deployed, before accounts carried a version byte.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MemberV0 {
+    pub wallet: Pubkey,
+    pub telegram_id: String,
+    pub shares: u64,
+    pub total_contributed: u64,
+    pub is_active: bool,
+}
+
+impl MemberV0 {
+    pub const SPACE: usize = DISCRIMINATOR
+        + 32
+        + (4 + MAX_STRING_LENGTH)
+        + 8
+        + 8
+        + 1;
+
+    /// The current balance becomes the first checkpoint, so the member can
+    /// vote on proposals created after the migration. Rewards accrue from the
+    /// fund's migration, since these shares were counted all along; rounds
+    /// started before this member was migrated did not count them.
+    pub fn migrate(self, fund: &Fund, slot: u64) -> Member {
+        let checkpoints = if self.shares > 0 {
+            vec![ShareCheckpoint { slot, shares: self.shares }]
+        } else {
+            Vec::new()
+        };
+
+        Member {
+            version: Member::VERSION,
+            wallet: self.wallet,
+            telegram_id: self.telegram_id,
+            shares: self.shares,
+            total_contributed: self.total_contributed,
+            is_active: self.is_active,
+            checkpoints,
+            delegate: None,
+            delegation_slot: 0,
+            delegator_count: 0,
+            queued_shares: 0,
+            lots: Vec::new(),
+            joined_round: fund.round_count,
+            last_paid_round: None,
+            reward_debt: 0,
+            pending_rewards: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{fund, member};
+
+    /// Account data as the program stored it: discriminator, body, then
+    /// zero padding up to the allocated size.
+    fn account_data(discriminator: &[u8], body: Vec<u8>, space: usize) -> Vec<u8> {
+        let mut data = discriminator.to_vec();
+        data.extend(body);
+        data.resize(space, 0);
+        data
+    }
+
+    fn fund_v0(is_active: bool) -> FundV0 {
+        FundV0 {
+            authority: Pubkey::new_unique(),
+            group_id: "-1001234567890".to_string(),
+            fund_name: "Degen Club".to_string(),
+            total_shares: 5_000,
+            total_value: 7_500,
+            min_contribution: 100,
+            trading_fee_bps: 200,
+            is_active,
+            bump: 0,
+        }
+    }
+
+    fn fund_v0_data(fund: &FundV0) -> Vec<u8> {
+        account_data(Fund::DISCRIMINATOR, fund.try_to_vec().unwrap(), FundV0::SPACE)
+    }
+
+    fn current_fund_data(fund: &Fund) -> Vec<u8> {
+        let mut data = Vec::new();
+        fund.try_serialize(&mut data).unwrap();
+        data.resize(Fund::SPACE, 0);
+        data
+    }
+
+    #[test]
+    fn v0_fund_migrates_with_balances_and_address() {
+        let mut old = fund_v0(true);
+        let (address, bump) =
+            Pubkey::find_program_address(&[b"fund", old.group_id.as_bytes()], &crate::ID);
+        old.bump = bump;
+
+        let (from_version, fund) = Fund::migrate_from(&fund_v0_data(&old), 42).unwrap();
+
+        assert_eq!(from_version, 0);
+        assert_eq!(fund.version, Fund::VERSION);
+        assert_eq!(fund.authority, old.authority);
+        assert_eq!(fund.trader, old.authority);
+        assert_eq!(fund.group_id, old.group_id);
+        assert_eq!(fund.total_shares, 5_000);
+        assert_eq!(fund.total_value, 7_500);
+        assert_eq!(fund.min_contribution, 100);
+        assert_eq!(fund.trading_fee_bps, 200);
+        assert_eq!(fund.status, FundStatus::Active);
+        assert_eq!(fund.redemptions.started_at, 42);
+        assert!(fund.legacy_address);
+        assert!(fund.has_address(&address));
+    }
+
+    #[test]
+    fn inactive_v0_fund_migrates_paused() {
+        let (_, fund) = Fund::migrate_from(&fund_v0_data(&fund_v0(false)), 0).unwrap();
+        assert_eq!(fund.status, FundStatus::Paused);
+    }
+
+    #[test]
+    fn v1_fund_migrates_keeping_its_state() {
+        let mut old = fund_v0(true).migrate(42);
+        let trader = Pubkey::new_unique();
+        old.trader = trader;
+        old.proposal_count = 4;
+        old.round_count = 2;
+        old.acc_reward_per_share = 1_234;
+        old.reward_reserve = 900;
+        old.status = FundStatus::WindingDown;
+        old.fundraising = Some(FundraisingParams { target: 1, hard_cap: 2, deadline: 3 });
+        let group_id = old.group_id.clone();
+        let data = account_data(Fund::DISCRIMINATOR, old.try_to_vec().unwrap(), FundV0::SPACE + 512);
+
+        let (from_version, fund) = Fund::migrate_from(&data, 0).unwrap();
+
+        assert_eq!(from_version, 1);
+        assert_eq!(fund.version, Fund::VERSION);
+        assert_eq!(fund.trader, trader);
+        assert_eq!(fund.proposal_count, 4);
+        assert_eq!(fund.round_count, 2);
+        assert_eq!(fund.acc_reward_per_share, 1_234);
+        assert_eq!(fund.reward_reserve, 900);
+        assert_eq!(fund.status, FundStatus::WindingDown);
+        assert_eq!(fund.fundraising, Some(FundraisingParams { target: 1, hard_cap: 2, deadline: 3 }));
+        assert_eq!(fund.redemptions.started_at, 42);
+        assert_eq!(fund.group_hash, Group::hash_id(&group_id));
+        assert!(fund.legacy_address);
+    }
+
+    #[test]
+    fn v2_fund_migrates_keeping_its_address_with_limits_disabled() {
+        let group_id = "-1005555555555".to_string();
+        let group_hash = Group::hash_id(&group_id);
+        let (address, bump) = Pubkey::find_program_address(
+            &[b"fund", &group_hash, &3u64.to_le_bytes()],
+            &crate::ID,
+        );
+        let mut v1 = fund_v0(true).migrate(0);
+        v1.version = 2;
+        v1.group_id = group_id;
+        v1.bump = bump;
+        v1.total_value = 8_000;
+        let old = FundV2 { v1, group_hash, fund_index: 3, legacy_address: false };
+        let data = account_data(Fund::DISCRIMINATOR, old.try_to_vec().unwrap(), Fund::SPACE - 30);
+
+        let (from_version, fund) = Fund::migrate_from(&data, 0).unwrap();
+
+        assert_eq!(from_version, 2);
+        assert_eq!(fund.version, Fund::VERSION);
+        assert_eq!(fund.total_value, 8_000);
+        assert_eq!(fund.fund_index, 3);
+        assert!(!fund.legacy_address);
+        assert!(fund.has_address(&address));
+        assert_eq!(fund.trade_limits.max_trade_bps, 0);
+        assert_eq!(fund.trade_limits.max_daily_volume, 0);
+    }
+
+    #[test]
+    fn v3_fund_migrates_keeping_its_limits() {
+        let mut v1 = fund_v0(true).migrate(0);
+        v1.version = 3;
+        v1.dividend_count = 2;
+        let v2 = FundV2 { v1, group_hash: [7; 32], fund_index: 1, legacy_address: false };
+        let limits = TradeLimits {
+            max_trade_bps: 500,
+            max_daily_volume: 1_000,
+            ..TradeLimits::default()
+        };
+        let old = FundV3 { v2, trade_limits: limits };
+        let data = account_data(Fund::DISCRIMINATOR, old.try_to_vec().unwrap(), Fund::SPACE - 4);
+
+        let (from_version, fund) = Fund::migrate_from(&data, 0).unwrap();
+
+        assert_eq!(from_version, 3);
+        assert_eq!(fund.version, Fund::VERSION);
+        assert_eq!(fund.fund_index, 1);
+        assert_eq!(fund.dividend_count, 2);
+        assert_eq!(fund.trade_limits.max_trade_bps, 500);
+        assert_eq!(fund.trade_limits.max_daily_volume, 1_000);
+        assert_eq!(fund.open_dividends, 0);
+    }
+
+    #[test]
+    fn migrated_fund_round_trips_in_current_layout() {
+        let (_, fund) = Fund::migrate_from(&fund_v0_data(&fund_v0(true)), 0).unwrap();
+        let data = current_fund_data(&fund);
+        assert_eq!(data.len(), Fund::SPACE);
+
+        let loaded = Fund::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(loaded.total_value, fund.total_value);
+        assert_eq!(loaded.group_id, fund.group_id);
+    }
+
+    #[test]
+    fn current_fund_is_already_migrated() {
+        let result = Fund::migrate_from(&current_fund_data(&fund()), 0);
+        assert_eq!(result.err(), Some(CustomError::AlreadyMigrated.into()));
+    }
+
+    #[test]
+    fn unknown_fund_version_is_rejected() {
+        let mut data = current_fund_data(&fund());
+        data[DISCRIMINATOR] = u8::MAX;
+        let result = Fund::migrate_from(&data, 0);
+        assert_eq!(result.err(), Some(CustomError::UnknownAccountLayout.into()));
+    }
+
+    #[test]
+    fn foreign_account_is_rejected() {
+        let data = account_data(Member::DISCRIMINATOR, Vec::new(), FundV0::SPACE);
+        let result = Fund::migrate_from(&data, 0);
+        assert_eq!(result.err(), Some(CustomError::UnknownAccountLayout.into()));
+    }
+
+    #[test]
+    fn v1_group_migrates_with_admins_and_funds() {
+        let group_id = "-1009876543210".to_string();
+        let group_hash = Group::hash_id(&group_id);
+        let (address, bump) = Pubkey::find_program_address(&[b"group", &group_hash], &crate::ID);
+        let old = GroupV1 { version: 1, group_hash, group_id, fund_count: 2, bump };
+        let data = account_data(Group::DISCRIMINATOR, old.try_to_vec().unwrap(), 90);
+        let admin = Pubkey::new_unique();
+
+        let (from_version, group) = Group::migrate_from(&data, vec![admin]).unwrap();
+
+        assert_eq!(from_version, 1);
+        assert_eq!(group.version, Group::VERSION);
+        assert_eq!(group.fund_count, 2);
+        assert_eq!(group.admins, vec![admin]);
+        assert_eq!(group.default_policies, GroupPolicies::default());
+        assert!(group.has_address(&address));
+
+        let (second_fund, _) = Pubkey::find_program_address(
+            &[b"fund", &group_hash, &1u64.to_le_bytes()],
+            &crate::ID,
+        );
+        assert_eq!(group.funds.len(), 2);
+        assert_eq!(group.funds[1], second_fund);
+
+        let mut migrated = Vec::new();
+        group.try_serialize(&mut migrated).unwrap();
+        assert!(migrated.len() <= Group::space(group.funds.len()));
+    }
+
+    #[test]
+    fn current_group_is_already_migrated() {
+        let old = GroupV1 {
+            version: 1,
+            group_hash: [0; 32],
+            group_id: "g".to_string(),
+            fund_count: 0,
+            bump: 0,
+        };
+        let mut data = account_data(Group::DISCRIMINATOR, old.try_to_vec().unwrap(), 90);
+        let (_, group) = Group::migrate_from(&data, vec![Pubkey::new_unique()]).unwrap();
+        data.clear();
+        group.try_serialize(&mut data).unwrap();
+
+        let result = Group::migrate_from(&data, Vec::new());
+        assert_eq!(result.err(), Some(CustomError::AlreadyMigrated.into()));
+    }
+
+    #[test]
+    fn v0_member_migrates_with_checkpoint() {
+        let old = MemberV0 {
+            wallet: Pubkey::new_unique(),
+            telegram_id: "12345".to_string(),
+            shares: 300,
+            total_contributed: 450,
+            is_active: true,
+        };
+        let data = account_data(Member::DISCRIMINATOR, old.try_to_vec().unwrap(), MemberV0::SPACE);
+        let mut fund = fund();
+        fund.round_count = 3;
+
+        let (from_version, member) = Member::migrate_from(&data, &fund, 99).unwrap();
+
+        assert_eq!(from_version, 0);
+        assert_eq!(member.version, Member::VERSION);
+        assert_eq!(member.wallet, old.wallet);
+        assert_eq!(member.shares, 300);
+        assert_eq!(member.total_contributed, 450);
+        assert_eq!(member.shares_at(99), 300);
+        assert_eq!(member.joined_round, 3);
+    }
+
+    #[test]
+    fn current_member_is_already_migrated() {
+        let mut data = Vec::new();
+        member().try_serialize(&mut data).unwrap();
+        data.resize(Member::SPACE, 0);
+
+        let result = Member::migrate_from(&data, &fund(), 0);
+        assert_eq!(result.err(), Some(CustomError::AlreadyMigrated.into()));
+    }
+}
@@ -1,5 +1,29 @@
+pub mod config;
+pub mod group;
 pub mod fund;
 pub mod member;
+pub mod governance;
+pub mod timelock;
+pub mod multisig;
+pub mod redemption;
+pub mod asset;
+pub mod allowlist;
+pub mod distribution;
+pub mod dividend;
+pub mod metadata;
+pub mod migration;
 
+pub use config::*;
+pub use group::*;
 pub use fund::*;
 pub use member::*;
+pub use governance::*;
+pub use timelock::*;
+pub use multisig::*;
+pub use redemption::*;
+pub use asset::*;
+pub use allowlist::*;
+pub use distribution::*;
+pub use dividend::*;
+pub use metadata::*;
+pub use migration::*;
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::state::{FundAction, ProposalAction};
+
+/// M-of-N signer set that can hold a fund's authority.
+#[account]
+pub struct Multisig {
+    pub version: u8,
+    pub fund: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub nonce: u64,
+    pub transaction_count: u64,
+    pub bump: u8,
+}
+
+impl Multisig {
+    pub const VERSION: u8 = 1;
+
+    pub const SPACE: usize = DISCRIMINATOR
+        + 1
+        + 32
+        + (4 + 32 * MAX_MULTISIG_SIGNERS)
+        + 1
+        + 8
+        + 8
+        + 1;
+
+    pub fn signer_index(&self, key: &Pubkey) -> Option<usize> {
+        self.signers.iter().position(|signer| signer == key)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum MultisigAction {
+    Fund(FundAction),
+    SetSigners { signers: Vec<Pubkey>, threshold: u8 },
+    /// Approval consumed by the instruction that carries the action out,
+    /// the way a passed proposal is; `execute_multisig_transaction` never
+    /// runs it.
+    Approve(ProposalAction),
+}
+
+impl MultisigAction {
+    const SET_SIGNERS_SPACE: usize = 4 + 32 * MAX_MULTISIG_SIGNERS + 1;
+
+    /// Variant tag plus the largest payload.
+    pub const SPACE: usize = 1 + if Self::SET_SIGNERS_SPACE > ProposalAction::SPACE {
+        Self::SET_SIGNERS_SPACE
+    } else {
+        ProposalAction::SPACE
+    };
+}
+
+#[account]
+pub struct MultisigTransaction {
+    pub version: u8,
+    pub multisig: Pubkey,
+    pub transaction_id: u64,
+    pub nonce: u64,
+    pub action: MultisigAction,
+    pub approvals: Vec<bool>,
+    pub approved_at: i64,
+    pub executed: bool,
+    pub bump: u8,
+}
+
+impl MultisigTransaction {
+    pub const VERSION: u8 = 1;
+
+    pub const SPACE: usize = DISCRIMINATOR
+        + 1
+        + 32
+        + 8
+        + 8
+        + MultisigAction::SPACE
+        + (4 + MAX_MULTISIG_SIGNERS)
+        + 8
+        + 1
+        + 1;
+
+    pub fn approval_count(&self) -> usize {
+        self.approvals.iter().filter(|approved| **approved).count()
+    }
+}
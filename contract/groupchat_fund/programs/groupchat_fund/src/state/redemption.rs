@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+/// Redemption queue bookkeeping for the fund's current epoch.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct RedemptionEpoch {
+    pub epoch: u64,
+    pub started_at: i64,
+    pub duration: i64,
+    pub pending_requests: u32,
+    pub queued_shares: u64,
+    pub settling: bool,
+    pub closing_value: u64,
+    pub closing_shares: u64,
+    pub fill_bps: u16,
+    pub carried_requests: u32,
+}
+
+impl RedemptionEpoch {
+    pub const SPACE: usize = 8 + 8 + 8 + 4 + 8 + 1 + 8 + 8 + 2 + 4;
+
+    pub fn ends_at(&self) -> Option<i64> {
+        self.started_at.checked_add(self.duration)
+    }
+
+    /// Requests can be queued or cancelled until the epoch's cutoff; after
+    /// that the queue is fixed for settlement.
+    pub fn accepts_requests(&self, now: i64) -> bool {
+        !self.settling && self.ends_at().is_some_and(|ends_at| now < ends_at)
+    }
+}
+
+/// Caps how much NAV can be redeemed per rolling window. A `gate_bps` of
+/// zero disables the gate.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct WithdrawalGate {
+    pub gate_bps: u16,
+    pub window_seconds: i64,
+    pub window_started_at: i64,
+    pub window_capacity: u64,
+    pub window_redeemed: u64,
+}
+
+impl WithdrawalGate {
+    pub const SPACE: usize = 2 + 8 + 8 + 8 + 8;
+
+    pub fn is_enabled(&self) -> bool {
+        self.gate_bps > 0
+    }
+
+    /// Opens a new window once the current one has run out. Capacity is fixed
+    /// from the NAV at the start of the window.
+    pub fn refresh(&mut self, now: i64, nav: u64) {
+        if !self.is_enabled() {
+            return;
+        }
+        let window_ends_at = self.window_started_at.saturating_add(self.window_seconds);
+        if self.window_started_at == 0 || now >= window_ends_at {
+            self.window_started_at = now;
+            self.window_redeemed = 0;
+            self.window_capacity = ((nav as u128) * (self.gate_bps as u128) / BPS_DENOMINATOR) as u64;
+        }
+    }
+
+    pub fn remaining(&self) -> u64 {
+        if !self.is_enabled() {
+            return u64::MAX;
+        }
+        self.window_capacity.saturating_sub(self.window_redeemed)
+    }
+
+    pub fn record(&mut self, amount: u64) {
+        if self.is_enabled() {
+            self.window_redeemed = self.window_redeemed.saturating_add(amount);
+        }
+    }
+}
+
+#[account]
+pub struct RedemptionRequest {
+    pub version: u8,
+    pub fund: Pubkey,
+    pub wallet: Pubkey,
+    pub shares: u64,
+    pub epoch: u64,
+    pub requested_at: i64,
+    pub bump: u8,
+}
+
+impl RedemptionRequest {
+    pub const VERSION: u8 = 1;
+
+    pub const SPACE: usize = DISCRIMINATOR
+        + 1
+        + 32
+        + 32
+        + 8
+        + 8
+        + 8
+        + 1;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn epoch() -> RedemptionEpoch {
+        RedemptionEpoch {
+            started_at: 1_000,
+            duration: 100,
+            ..RedemptionEpoch::default()
+        }
+    }
+
+    #[test]
+    fn requests_close_at_the_epoch_cutoff() {
+        let epoch = epoch();
+
+        assert!(epoch.accepts_requests(1_000));
+        assert!(epoch.accepts_requests(1_099));
+        assert!(!epoch.accepts_requests(1_100));
+        assert!(!epoch.accepts_requests(2_000));
+    }
+
+    #[test]
+    fn settling_epochs_take_no_requests() {
+        let epoch = RedemptionEpoch { settling: true, ..epoch() };
+
+        assert!(!epoch.accepts_requests(1_000));
+    }
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::state::{ConfigPolicy, FundConfigUpdate};
+
+/// Privileged fund changes that can be delayed behind a timelock.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ActionKind {
+    PauseFund,
+    ResumeFund,
+    CloseFund,
+    SetTimelockDelay,
+    SetTrader,
+    ProposeAuthority,
+    CancelAuthorityTransfer,
+    RenounceAuthority,
+    SetGuardian,
+    UpdateConfig,
+    SetConfigPolicy,
+    BeginWindDown,
+    SetTradeLimits,
+    DeclareRewards,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum FundAction {
+    PauseFund,
+    ResumeFund,
+    CloseFund,
+    SetTimelockDelay { kind: ActionKind, delay: i64 },
+    SetTrader { trader: Pubkey },
+    ProposeAuthority { new_authority: Pubkey },
+    CancelAuthorityTransfer,
+    RenounceAuthority,
+    SetGuardian { guardian: Option<Pubkey> },
+    UpdateConfig(FundConfigUpdate),
+    SetConfigPolicy { policy: ConfigPolicy },
+    BeginWindDown,
+    SetTradeLimits { max_trade_bps: u16, max_daily_volume: u64 },
+    DeclareRewards { amount: u64 },
+}
+
+impl FundAction {
+    /// Variant tag plus the largest payload (`UpdateConfig`).
+    pub const SPACE: usize = 1 + FundConfigUpdate::SPACE;
+
+    pub fn kind(&self) -> ActionKind {
+        match self {
+            FundAction::PauseFund => ActionKind::PauseFund,
+            FundAction::ResumeFund => ActionKind::ResumeFund,
+            FundAction::CloseFund => ActionKind::CloseFund,
+            FundAction::SetTimelockDelay { .. } => ActionKind::SetTimelockDelay,
+            FundAction::SetTrader { .. } => ActionKind::SetTrader,
+            FundAction::ProposeAuthority { .. } => ActionKind::ProposeAuthority,
+            FundAction::CancelAuthorityTransfer => ActionKind::CancelAuthorityTransfer,
+            FundAction::RenounceAuthority => ActionKind::RenounceAuthority,
+            FundAction::SetGuardian { .. } => ActionKind::SetGuardian,
+            FundAction::UpdateConfig(_) => ActionKind::UpdateConfig,
+            FundAction::SetConfigPolicy { .. } => ActionKind::SetConfigPolicy,
+            FundAction::BeginWindDown => ActionKind::BeginWindDown,
+            FundAction::SetTradeLimits { .. } => ActionKind::SetTradeLimits,
+            FundAction::DeclareRewards { .. } => ActionKind::DeclareRewards,
+        }
+    }
+}
+
+#[account]
+pub struct QueuedAction {
+    pub version: u8,
+    pub fund: Pubkey,
+    pub action_id: u64,
+    pub action: FundAction,
+    pub queued_at: i64,
+    pub eta: i64,
+    pub bump: u8,
+}
+
+impl QueuedAction {
+    pub const VERSION: u8 = 1;
+
+    pub const SPACE: usize = DISCRIMINATOR
+        + 1
+        + 32
+        + 8
+        + FundAction::SPACE
+        + 8
+        + 8
+        + 1;
+
+    /// The delay the action was queued with. Later changes to the fund's
+    /// delays do not move `eta`, so this is what it actually waits.
+    pub fn delay(&self) -> i64 {
+        self.eta.saturating_sub(self.queued_at)
+    }
+}
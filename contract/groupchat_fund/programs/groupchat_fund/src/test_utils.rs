@@ -0,0 +1,72 @@
+//! Account builders for unit tests.
+
+use anchor_lang::prelude::*;
+use crate::constants::MAX_ACTION_KINDS;
+use crate::state::{
+    ConfigPolicy, Fund, FundStatus, Member, RedemptionEpoch, TradeLimits, WithdrawalGate,
+};
+
+/// An active fund with no shares, fees or limits.
+pub(crate) fn fund() -> Fund {
+    Fund {
+        version: Fund::VERSION,
+        authority: Pubkey::new_unique(),
+        trader: Pubkey::new_unique(),
+        group_id: "test-group".to_string(),
+        fund_name: "Test Fund".to_string(),
+        total_shares: 0,
+        total_value: 0,
+        min_contribution: 1,
+        trading_fee_bps: 0,
+        status: FundStatus::Active,
+        bump: 255,
+        proposal_count: 0,
+        timelock_delays: [0; MAX_ACTION_KINDS],
+        action_count: 0,
+        pending_authority: None,
+        members_only: false,
+        guardian: None,
+        guardian_paused_at: 0,
+        config_policy: ConfigPolicy::AuthorityOnly,
+        redemptions: RedemptionEpoch::default(),
+        lockup_seconds: 0,
+        withdrawal_gate: WithdrawalGate::default(),
+        asset_count: 0,
+        exit_fee_bps: 0,
+        member_count: 0,
+        round_count: 0,
+        active_round: None,
+        dividend_count: 0,
+        acc_reward_per_share: 0,
+        reward_reserve: 0,
+        status_before_pause: FundStatus::Active,
+        fundraising: None,
+        group_hash: [0; 32],
+        fund_index: 0,
+        legacy_address: false,
+        trade_limits: TradeLimits::default(),
+        open_dividends: 0,
+    }
+}
+
+/// An active member holding nothing.
+pub(crate) fn member() -> Member {
+    Member {
+        version: Member::VERSION,
+        wallet: Pubkey::new_unique(),
+        telegram_id: "12345".to_string(),
+        shares: 0,
+        total_contributed: 0,
+        is_active: true,
+        checkpoints: Vec::new(),
+        delegate: None,
+        delegation_slot: 0,
+        delegator_count: 0,
+        queued_shares: 0,
+        lots: Vec::new(),
+        joined_round: 0,
+        last_paid_round: None,
+        reward_debt: 0,
+        pending_rewards: 0,
+    }
+}
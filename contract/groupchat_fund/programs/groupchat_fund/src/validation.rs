@@ -0,0 +1,134 @@
+//! Checks on instruction arguments. Handlers run these before touching any
+//! account so bad input fails with a specific error rather than during
+//! serialization or deep inside the instruction.
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::ErrorCode as CustomError;
+
+
+pub(crate) fn validate_fund_name(fund_name: &str) -> Result<()> {
+    require!(!fund_name.is_empty(), CustomError::EmptyName);
+    require!(fund_name.len() <= MAX_STRING_LENGTH, CustomError::NameTooLong);
+    Ok(())
+}
+
+
+pub(crate) fn validate_group_id(group_id: &str) -> Result<()> {
+    require!(
+        !group_id.is_empty() && group_id.len() <= MAX_GROUP_ID_LENGTH,
+        CustomError::InvalidGroupId
+    );
+    Ok(())
+}
+
+
+/// Between one and `MAX_GROUP_ADMINS` distinct keys.
+pub(crate) fn validate_group_admins(admins: &[Pubkey]) -> Result<()> {
+    require!(
+        !admins.is_empty() && admins.len() <= MAX_GROUP_ADMINS,
+        CustomError::InvalidGroupAdmins
+    );
+    for (index, admin) in admins.iter().enumerate() {
+        require!(!admins[..index].contains(admin), CustomError::InvalidGroupAdmins);
+    }
+    Ok(())
+}
+
+
+pub(crate) fn validate_telegram_id(telegram_id: &str) -> Result<()> {
+    require!(!telegram_id.is_empty(), CustomError::EmptyTelegramId);
+    require!(telegram_id.len() <= MAX_STRING_LENGTH, CustomError::TelegramIdTooLong);
+    Ok(())
+}
+
+
+pub(crate) fn validate_description(description: &str) -> Result<()> {
+    require!(
+        description.len() <= MAX_DESCRIPTION_LENGTH,
+        CustomError::DescriptionTooLong
+    );
+    Ok(())
+}
+
+
+/// `max_bps` is the cap for this particular fee, never above 100%.
+pub(crate) fn validate_fee_bps(fee_bps: u16, max_bps: u16) -> Result<()> {
+    require!(
+        fee_bps <= max_bps && fee_bps as u128 <= BPS_DENOMINATOR,
+        CustomError::InvalidFeeBps
+    );
+    Ok(())
+}
+
+
+pub(crate) fn validate_amount(amount: u64) -> Result<()> {
+    require!(amount > 0, CustomError::ZeroAmount);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rejects(result: Result<()>, error: CustomError) {
+        assert_eq!(result.err(), Some(error.into()));
+    }
+
+    #[test]
+    fn fund_name_must_be_non_empty_and_bounded() {
+        rejects(validate_fund_name(""), CustomError::EmptyName);
+        rejects(validate_fund_name(&"n".repeat(MAX_STRING_LENGTH + 1)), CustomError::NameTooLong);
+        assert!(validate_fund_name(&"n".repeat(MAX_STRING_LENGTH)).is_ok());
+    }
+
+    #[test]
+    fn group_id_must_be_non_empty_and_bounded() {
+        rejects(validate_group_id(""), CustomError::InvalidGroupId);
+        rejects(validate_group_id(&"g".repeat(MAX_GROUP_ID_LENGTH + 1)), CustomError::InvalidGroupId);
+        assert!(validate_group_id(&"g".repeat(MAX_GROUP_ID_LENGTH)).is_ok());
+    }
+
+    #[test]
+    fn group_admins_must_be_distinct_and_bounded() {
+        let admin = Pubkey::new_unique();
+        rejects(validate_group_admins(&[]), CustomError::InvalidGroupAdmins);
+        rejects(validate_group_admins(&[admin, admin]), CustomError::InvalidGroupAdmins);
+        let too_many: Vec<Pubkey> = (0..=MAX_GROUP_ADMINS).map(|_| Pubkey::new_unique()).collect();
+        rejects(validate_group_admins(&too_many), CustomError::InvalidGroupAdmins);
+        assert!(validate_group_admins(&too_many[..MAX_GROUP_ADMINS]).is_ok());
+    }
+
+    #[test]
+    fn telegram_id_must_be_non_empty_and_bounded() {
+        rejects(validate_telegram_id(""), CustomError::EmptyTelegramId);
+        rejects(
+            validate_telegram_id(&"1".repeat(MAX_STRING_LENGTH + 1)),
+            CustomError::TelegramIdTooLong,
+        );
+        assert!(validate_telegram_id("12345").is_ok());
+    }
+
+    #[test]
+    fn description_is_bounded() {
+        rejects(
+            validate_description(&"d".repeat(MAX_DESCRIPTION_LENGTH + 1)),
+            CustomError::DescriptionTooLong,
+        );
+        assert!(validate_description("").is_ok());
+    }
+
+    #[test]
+    fn fee_is_capped_by_its_maximum_and_by_100_percent() {
+        rejects(validate_fee_bps(501, 500), CustomError::InvalidFeeBps);
+        rejects(validate_fee_bps(10_001, u16::MAX), CustomError::InvalidFeeBps);
+        assert!(validate_fee_bps(500, 500).is_ok());
+        assert!(validate_fee_bps(10_000, u16::MAX).is_ok());
+    }
+
+    #[test]
+    fn amount_must_be_positive() {
+        rejects(validate_amount(0), CustomError::ZeroAmount);
+        assert!(validate_amount(1).is_ok());
+    }
+}